@@ -3,7 +3,16 @@
 #import themes.simple: *
 
 #let tmil_tr(en, pt_br, zh_cn) = (en, pt_br, zh_cn)
-#let tmil_langs = ("pt-BR", "zh-CN", "es")
+// `--input lang=<site lang code>` keeps only that language, for the
+// per-language PDF editions; without it every language is shown.
+#let tmil_langs = {
+  let all = ("pt-BR", "zh-CN", "es")
+  let only = (en: "es", "pt-br": "pt-BR", zh: "zh-CN").at(
+    sys.inputs.at("lang", default: ""),
+    default: none,
+  )
+  if only == none { all } else { (only,) }
+}
 
 #let tmil_month_label(year, month) = {
   str(year) + "-" + if month < 10 { "0" + str(month) } else { str(month) }
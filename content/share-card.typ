@@ -0,0 +1,23 @@
+// Open Graph share card of a page or post, rendered by `share_card.rs` at
+// 2 px/pt into a 1200×630 PNG. Text comes in through `--input`.
+#let title = sys.inputs.at("title", default: "Lince")
+#let kicker = sys.inputs.at("kicker", default: "")
+#let date = sys.inputs.at("date", default: "")
+
+#set page(width: 600pt, height: 315pt, margin: 40pt, fill: rgb("#0b0b0b"))
+#set text(fill: rgb("#f5f5f5"), size: 16pt)
+#set par(leading: 0.5em)
+
+#place(top + right, image("/output/assets/logo/white.svg", width: 56pt))
+
+#align(bottom + left)[
+  #if kicker != "" [
+    #text(size: 12pt, fill: rgb("#a0a0a0"), tracking: 0.08em, upper(kicker))
+    #v(4pt)
+  ]
+  #block(width: 85%, text(size: 34pt, weight: "bold", title))
+  #if date != "" [
+    #v(6pt)
+    #text(size: 13pt, fill: rgb("#a0a0a0"), date)
+  ]
+]
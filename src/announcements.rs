@@ -0,0 +1,264 @@
+//! Ready-to-paste announcements of the posts published since the previous
+//! build: one snippet per platform and language under `output-announcements/`,
+//! which is not deployed.
+
+use crate::{
+    config::SiteConfig,
+    i18n::Translations,
+    pages::blog::{BLOG_POSTS_ROOT, BlogPost, get_blog_posts, post_video_url, tmil_month},
+    routes::Route,
+    tmil::read_tmil,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fs, io, path::Path};
+
+const ANNOUNCEMENTS_DIR: &str = "output-announcements";
+/// Slugs of every post the previous build published.
+const MANIFEST_PATH: &str = "output-announcements/manifest.toml";
+/// Mastodon counts every link as this many characters, whatever its length.
+const MASTODON_URL_LENGTH: usize = 23;
+
+#[derive(Default, Deserialize, Serialize)]
+struct Manifest {
+    #[serde(default)]
+    posts: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Platform {
+    Matrix,
+    Discord,
+    Mastodon,
+}
+
+impl Platform {
+    const ALL: [Platform; 3] = [Platform::Matrix, Platform::Discord, Platform::Mastodon];
+
+    fn name(self) -> &'static str {
+        match self {
+            Platform::Matrix => "matrix",
+            Platform::Discord => "discord",
+            Platform::Mastodon => "mastodon",
+        }
+    }
+
+    /// Longest message the platform accepts; Matrix has no practical limit.
+    fn limit(self) -> Option<usize> {
+        match self {
+            Platform::Matrix => None,
+            Platform::Discord => Some(2000),
+            Platform::Mastodon => Some(500),
+        }
+    }
+
+    /// Length of `message` as the platform counts it.
+    fn length(self, message: &str) -> usize {
+        let chars = message.chars().count();
+        if self != Platform::Mastodon {
+            return chars;
+        }
+        message
+            .split_whitespace()
+            .filter(|word| word.starts_with("https://") || word.starts_with("http://"))
+            .fold(chars, |len, url| {
+                len + MASTODON_URL_LENGTH - url.chars().count()
+            })
+    }
+
+    fn fits(self, message: &str) -> bool {
+        self.limit()
+            .is_none_or(|limit| self.length(message) <= limit)
+    }
+
+    /// Matrix and Discord render Markdown; Mastodon shows it verbatim.
+    fn bold(self, text: &str) -> String {
+        match self {
+            Platform::Mastodon => text.to_string(),
+            _ => format!("**{text}**"),
+        }
+    }
+}
+
+/// What is announced about one post in one language.
+struct Announcement {
+    title: String,
+    url: String,
+    video_url: Option<String>,
+    /// Item headings of a TMIL, included while the platform's limit allows.
+    highlights: Vec<String>,
+}
+
+impl Announcement {
+    fn new(t: &Translations, site: &SiteConfig, post: &BlogPost) -> Self {
+        let stem = post.slug.rsplit('/').next().unwrap_or_default();
+        let highlights = tmil_month(stem)
+            .and_then(|_| {
+                read_tmil(&Path::new(BLOG_POSTS_ROOT).join(format!("{}.typ", post.slug))).ok()
+            })
+            .map(|source| {
+                source
+                    .sections
+                    .iter()
+                    .flat_map(|section| &section.items)
+                    .filter_map(|item| item.heading.as_ref())
+                    .map(|heading| {
+                        let heading = heading.get(t.lang_code);
+                        match heading.subtitle.is_empty() || heading.subtitle == heading.title {
+                            true => heading.title.clone(),
+                            false => format!("{} — {}", heading.title, heading.subtitle),
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            title: post.localized_title(t),
+            url: Route::BlogPost(&post.slug).url(site, t.lang_code),
+            video_url: post_video_url(&post.slug),
+            highlights,
+        }
+    }
+
+    fn compose(&self, t: &Translations, platform: Platform) -> String {
+        let head = |title: &str| format!("{}\n{}", t.announce_new_post, platform.bold(title));
+        let mut tail = vec![self.url.clone()];
+        if let Some(video_url) = &self.video_url {
+            tail.push(format!("{}: {video_url}", t.blog_watch_video));
+        }
+        if platform == Platform::Mastodon {
+            tail.push("#Lince".to_string());
+        }
+        let tail = tail.join("\n");
+
+        // A title too long for the platform is cut; links never are.
+        let mut title = self.title.clone();
+        while !platform.fits(&format!("{}\n\n{tail}", head(&title))) {
+            title = title.trim_end_matches('…').to_string();
+            if title.pop().is_none() {
+                break;
+            }
+            title.push('…');
+        }
+        let head = head(&title);
+
+        let mut highlights = String::new();
+        for highlight in &self.highlights {
+            let line = format!("• {highlight}\n");
+            if !platform.fits(&format!("{head}\n\n{highlights}{line}\n{tail}")) {
+                break;
+            }
+            highlights.push_str(&line);
+        }
+        match highlights.is_empty() {
+            true => format!("{head}\n\n{tail}\n"),
+            false => format!("{head}\n\n{highlights}\n{tail}\n"),
+        }
+    }
+}
+
+fn read_manifest() -> Option<Manifest> {
+    let raw = fs::read_to_string(MANIFEST_PATH).ok()?;
+    toml::from_str(&raw)
+        .map_err(|e| eprintln!("Ignoring invalid {MANIFEST_PATH}: {e}"))
+        .ok()
+}
+
+/// Posts that are not in the previous build's manifest.
+fn unannounced<'a>(posts: &'a [BlogPost], manifest: &Manifest) -> Vec<&'a BlogPost> {
+    posts
+        .iter()
+        .filter(|post| !manifest.posts.contains(&post.slug))
+        .collect()
+}
+
+/// Write announcements for every post published since the last build, then
+/// record the current posts. Without a manifest nothing is announced, so a
+/// fresh checkout does not announce the whole archive.
+pub fn write_announcements(
+    site: &SiteConfig,
+    translations: &HashMap<&str, Translations>,
+) -> io::Result<()> {
+    let posts = get_blog_posts();
+    let mut languages: Vec<_> = translations.iter().collect();
+    languages.sort_by_key(|(lang_code, _)| **lang_code);
+
+    if let Some(manifest) = read_manifest() {
+        for post in unannounced(&posts, &manifest) {
+            let dir = Path::new(ANNOUNCEMENTS_DIR).join(&post.slug);
+            fs::create_dir_all(&dir)?;
+            for (lang_code, t) in &languages {
+                let announcement = Announcement::new(t, site, post);
+                for platform in Platform::ALL {
+                    let path = dir.join(format!("{}.{lang_code}.txt", platform.name()));
+                    fs::write(&path, announcement.compose(t, platform))?;
+                }
+            }
+            println!(
+                "Announcements for {} written to {}",
+                post.slug,
+                dir.display()
+            );
+        }
+    }
+
+    let manifest = Manifest {
+        posts: posts.into_iter().map(|post| post.slug).collect(),
+    };
+    fs::create_dir_all(ANNOUNCEMENTS_DIR)?;
+    let raw = toml::to_string(&manifest).map_err(io::Error::other)?;
+    fs::write(MANIFEST_PATH, raw)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Announcement, Manifest, Platform, unannounced};
+    use crate::{config::SiteConfig, i18n::get_translations, pages::blog::get_blog_posts};
+
+    #[test]
+    fn only_posts_missing_from_the_manifest_are_announced() {
+        let posts = get_blog_posts();
+        let manifest = Manifest {
+            posts: posts[1..].iter().map(|post| post.slug.clone()).collect(),
+        };
+        let new: Vec<&str> = unannounced(&posts, &manifest)
+            .iter()
+            .map(|post| post.slug.as_str())
+            .collect();
+        assert_eq!(new, [posts[0].slug.as_str()]);
+    }
+
+    #[test]
+    fn announcements_are_localized_and_fit_each_platform() {
+        let translations = get_translations();
+        let t = &translations["pt-br"];
+        let post = get_blog_posts()
+            .into_iter()
+            .find(|post| post.slug == "2026/2026_02_tmil")
+            .unwrap();
+        let site = SiteConfig::load().unwrap();
+        let mut announcement = Announcement::new(t, &site, &post);
+        assert!(
+            announcement
+                .highlights
+                .contains(&"Novo Logo — Feito pela Nica".to_string())
+        );
+
+        let matrix = announcement.compose(t, Platform::Matrix);
+        assert!(matrix.contains("**Este Mês na Lince | 2026-02**"));
+        assert!(matrix.contains("\nhttps://lince.social/blog/2026/2026_02_tmil.pt-br.html\n"));
+        assert!(matrix.contains("https://youtu.be/iH4L37GrPBE"));
+        assert!(matrix.contains("• Novo Logo — Feito pela Nica\n"));
+
+        // Mastodon counts the links as 23 characters and drops highlights that do not fit.
+        announcement.highlights = vec!["x".repeat(120); 10];
+        let mastodon = announcement.compose(t, Platform::Mastodon);
+        assert!(Platform::Mastodon.length(&mastodon) <= 500);
+        assert!(
+            mastodon.len() > 500 - 2 * 23,
+            "Links must count as 23 characters"
+        );
+        assert!(mastodon.ends_with("#Lince\n"));
+        assert!(!mastodon.contains("**"));
+    }
+}
@@ -0,0 +1,243 @@
+//! Build cache of Tinymist SVG sidecars and PDF export stamps
+//! (`output/assets/blog/.cache`) and the `cache stats` / `cache clear` subcommands.
+
+use crate::compiler::TYPST_LIBRARY_VERSION;
+use std::{
+    collections::{HashSet, hash_map::DefaultHasher},
+    fs,
+    hash::{Hash, Hasher},
+    io,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::OnceLock,
+};
+
+pub const SIDECAR_CACHE_DIR: &str = "output/assets/blog/.cache";
+
+/// `--version` of every tool whose output ends up in the cache, plus the
+/// linked Typst library. A tool
+/// upgrade changes the rendering, so it must invalidate cached sidecars.
+fn tool_versions() -> &'static str {
+    static VERSIONS: OnceLock<String> = OnceLock::new();
+    VERSIONS.get_or_init(|| {
+        ["tinymist", "typst"]
+            .into_iter()
+            .map(|tool| {
+                let version = Command::new(tool)
+                    .arg("--version")
+                    .output()
+                    .ok()
+                    .filter(|output| output.status.success())
+                    .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+                    .unwrap_or_else(|| "missing".to_string());
+                format!("{tool} {version}")
+            })
+            .chain([TYPST_LIBRARY_VERSION.to_string()])
+            .collect::<Vec<_>>()
+            .join("\n")
+    })
+}
+
+/// Fingerprint of a Typst document: its path, the tool versions and the
+/// contents of every file it depends on (`deps`, as reported by `typst --deps`).
+pub fn typst_fingerprint(source_path: &Path, deps: &[PathBuf]) -> Option<u64> {
+    let mut hasher = DefaultHasher::new();
+    source_path.to_string_lossy().hash(&mut hasher);
+    fs::read(source_path).ok()?.hash(&mut hasher);
+    tool_versions().hash(&mut hasher);
+
+    let mut deps: Vec<&PathBuf> = deps.iter().collect();
+    deps.sort();
+    deps.dedup();
+    for dep in deps {
+        dep.hash(&mut hasher);
+        // A dependency that disappeared still changes the fingerprint.
+        fs::read(dep).ok().hash(&mut hasher);
+    }
+    Some(hasher.finish())
+}
+
+/// 64-bit FNV-1a of `parts`, each prefixed with its length. Unlike
+/// `DefaultHasher` it is the same on every platform and Rust release, so it
+/// can name files that are committed or cached across builds.
+pub fn stable_hash<'a>(parts: impl IntoIterator<Item = &'a [u8]>) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+    let mut hash = OFFSET_BASIS;
+    for part in parts {
+        let len = (part.len() as u64).to_le_bytes();
+        for byte in len.iter().chain(part) {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(PRIME);
+        }
+    }
+    hash
+}
+
+/// Parse a `--deps-format zero` file: NUL-separated input paths.
+pub fn read_typst_deps(path: &Path) -> Vec<PathBuf> {
+    fs::read_to_string(path)
+        .unwrap_or_default()
+        .split('\0')
+        .filter(|dep| !dep.is_empty())
+        .map(PathBuf::from)
+        .collect()
+}
+
+fn cache_entries() -> Vec<(PathBuf, u64)> {
+    let Ok(entries) = fs::read_dir(SIDECAR_CACHE_DIR) else {
+        return Vec::new();
+    };
+    let mut files: Vec<(PathBuf, u64)> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let meta = entry.metadata().ok()?;
+            meta.is_file().then(|| (entry.path(), meta.len()))
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+/// Delete every cache entry not in `referenced`, returning how many files and
+/// bytes were removed.
+pub fn collect_garbage(referenced: &HashSet<PathBuf>) -> io::Result<(usize, u64)> {
+    let mut removed = (0, 0);
+    for (path, size) in cache_entries() {
+        if !referenced.contains(&path) {
+            fs::remove_file(&path)?;
+            removed.0 += 1;
+            removed.1 += size;
+        }
+    }
+    Ok(removed)
+}
+
+/// Post a cache entry belongs to (`{stem}-{fingerprint}.svg` → `stem`,
+/// `{stem}.pdf-{fingerprint}.stamp` → `stem.pdf`).
+fn entry_stem(path: &Path) -> String {
+    let name = path.file_stem().unwrap_or_default().to_string_lossy();
+    name.rsplit_once('-')
+        .map(|(stem, _)| stem.to_string())
+        .unwrap_or_else(|| name.to_string())
+}
+
+fn kib(bytes: u64) -> u64 {
+    bytes.div_ceil(1024)
+}
+
+fn print_stats() {
+    let entries = cache_entries();
+    let total: u64 = entries.iter().map(|(_, size)| size).sum();
+    println!(
+        "{SIDECAR_CACHE_DIR}: {} entries, {} KiB",
+        entries.len(),
+        kib(total)
+    );
+
+    let mut stems: Vec<(String, usize, u64)> = Vec::new();
+    for (path, size) in &entries {
+        let stem = entry_stem(path);
+        match stems.iter_mut().find(|(s, _, _)| *s == stem) {
+            Some((_, count, bytes)) => {
+                *count += 1;
+                *bytes += size;
+            }
+            None => stems.push((stem, 1, *size)),
+        }
+    }
+    for (stem, count, bytes) in stems {
+        // More than one fingerprint per post means superseded entries the next build removes.
+        let superseded = if count > 1 {
+            " (superseded entries)"
+        } else {
+            ""
+        };
+        println!("  {stem}: {count} entries, {} KiB{superseded}", kib(bytes));
+    }
+}
+
+fn clear() -> io::Result<()> {
+    let entries = cache_entries();
+    let total: u64 = entries.iter().map(|(_, size)| size).sum();
+    for (path, _) in &entries {
+        fs::remove_file(path)?;
+    }
+    println!(
+        "Removed {} entries ({} KiB) from {SIDECAR_CACHE_DIR}",
+        entries.len(),
+        kib(total)
+    );
+    Ok(())
+}
+
+/// `cache stats` / `cache clear`.
+pub fn run(args: &[&str]) -> Result<(), String> {
+    match args {
+        ["stats"] => {
+            print_stats();
+            Ok(())
+        }
+        ["clear"] => clear().map_err(|e| format!("Failed to clear {SIDECAR_CACHE_DIR}: {e}")),
+        _ => Err("Usage: website cache <stats|clear>".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{entry_stem, read_typst_deps, stable_hash, typst_fingerprint};
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn fingerprints_follow_every_dependency() {
+        let dir = std::env::temp_dir().join("lince-website-cache-test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let source = dir.join("post.typ");
+        let image = dir.join("photo.png");
+        std::fs::write(&source, "#image(\"photo.png\")").unwrap();
+        std::fs::write(&image, "v1").unwrap();
+
+        let deps_file = dir.join("post.deps");
+        std::fs::write(
+            &deps_file,
+            format!("{}\0{}\0", source.display(), image.display()),
+        )
+        .unwrap();
+        let deps = read_typst_deps(&deps_file);
+        assert_eq!(deps, [source.clone(), image.clone()]);
+
+        let before = typst_fingerprint(&source, &deps);
+        std::fs::write(&image, "v2").unwrap();
+        assert_ne!(before, typst_fingerprint(&source, &deps));
+        assert_ne!(
+            typst_fingerprint(&source, &deps),
+            typst_fingerprint(&source, std::slice::from_ref(&source))
+        );
+    }
+
+    #[test]
+    fn stable_hashes_do_not_change_between_builds() {
+        assert_eq!(stable_hash([]), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(
+            stable_hash([b"title".as_slice(), b"Roadmap"]),
+            stable_hash([b"title".as_slice(), b"Roadmap"])
+        );
+        // Length prefixes keep part boundaries apart.
+        assert_ne!(
+            stable_hash([b"ab".as_slice(), b"c"]),
+            stable_hash([b"a".as_slice(), b"bc"])
+        );
+    }
+
+    #[test]
+    fn cache_entries_are_grouped_by_post() {
+        let path =
+            PathBuf::from("output/assets/blog/.cache/2026__2026_01_tmil-f5da41da201fb5c7.svg");
+        assert_eq!(entry_stem(&path), "2026__2026_01_tmil");
+        assert_eq!(entry_stem(Path::new("plain.svg")), "plain");
+        assert_eq!(
+            entry_stem(Path::new("2026__2026_01_tmil.pdf-f5da41da201fb5c7.stamp")),
+            "2026__2026_01_tmil.pdf"
+        );
+    }
+}
@@ -0,0 +1,701 @@
+//! Typst compilation backends. Posts are compiled in-process through the
+//! `typst` crates when possible, falling back to the `typst`/`tinymist` CLIs.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::{Mutex, OnceLock},
+    time::{SystemTime, UNIX_EPOCH},
+};
+use typst::{
+    Feature, Library, LibraryExt, World,
+    diag::{FileError, FileResult, SourceDiagnostic},
+    foundations::{Bytes, Datetime, Dict, Duration, Output, Str, Value},
+    layout::Abs,
+    syntax::{FileId, RootedPath, Source, VirtualPath, VirtualRoot},
+    text::{Font, FontBook},
+    utils::{LazyHash, Scalar},
+};
+use typst_html::{HtmlDocument, HtmlOptions};
+use typst_kit::{
+    downloader::SystemDownloader,
+    fonts::{self, FontStore},
+    packages::SystemPackages,
+};
+use typst_layout::PagedDocument;
+use typst_pdf::PdfOptions;
+use typst_render::RenderOptions;
+use typst_svg::SvgOptions;
+
+/// Version of the `typst` crates linked into the in-process backend.
+pub const TYPST_LIBRARY_VERSION: &str = "typst-lib 0.15.1";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OutputFormat {
+    /// Semantic HTML (`typst compile --format html`).
+    Html,
+    /// One merged SVG document (`class="typst-doc"`).
+    Svg,
+}
+
+pub struct Compiled {
+    pub output: String,
+    /// Every file Typst read while compiling.
+    pub deps: Vec<PathBuf>,
+}
+
+pub trait TypstBackend: Send + Sync {
+    fn name(&self) -> &'static str;
+    fn compile(&self, source: &Path, format: OutputFormat) -> Result<Compiled, String>;
+    /// Compile to one SVG per page, with `inputs` visible through `sys.inputs`.
+    fn compile_pages(&self, source: &Path, inputs: &[(&str, &str)]) -> Result<Vec<String>, String>;
+    /// Compile to a PDF document, with `inputs` visible through `sys.inputs`.
+    fn compile_pdf(&self, source: &Path, inputs: &[(&str, &str)]) -> Result<Vec<u8>, String>;
+    /// Render the first page to a PNG at `pixels_per_pt`, with `inputs`
+    /// visible through `sys.inputs`.
+    fn compile_png(
+        &self,
+        source: &Path,
+        inputs: &[(&str, &str)],
+        pixels_per_pt: f32,
+    ) -> Result<Vec<u8>, String>;
+}
+
+/// The `typst` CLI for HTML, pages and PDF and the `tinymist` CLI for SVG.
+pub struct CliBackend;
+
+impl TypstBackend for CliBackend {
+    fn name(&self) -> &'static str {
+        "cli"
+    }
+
+    fn compile(&self, source: &Path, format: OutputFormat) -> Result<Compiled, String> {
+        match format {
+            OutputFormat::Html => cli_html(source),
+            OutputFormat::Svg => cli_svg(source),
+        }
+    }
+
+    fn compile_pages(&self, source: &Path, inputs: &[(&str, &str)]) -> Result<Vec<String>, String> {
+        let pages_dir = temp_path(source, "pages");
+        let _ = fs::remove_dir_all(&pages_dir);
+        fs::create_dir_all(&pages_dir).map_err(|e| e.to_string())?;
+
+        let mut command = Command::new("typst");
+        command.arg("compile").arg(source).arg("--root").arg(".");
+        for (key, value) in inputs {
+            command.arg("--input").arg(format!("{key}={value}"));
+        }
+        let output = command
+            .arg("--format")
+            .arg("svg")
+            .arg(pages_dir.join("{0p}.svg"))
+            .output()
+            .map_err(|_| {
+                "Typst CLI not found. Install it with 'cargo install typst-cli'".to_string()
+            })?;
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        }
+
+        let mut files: Vec<PathBuf> = fs::read_dir(&pages_dir)
+            .map_err(|e| e.to_string())?
+            .flatten()
+            .map(|entry| entry.path())
+            .collect();
+        // `{0p}` zero-pads page numbers, so names sort in page order.
+        files.sort();
+        let pages = files
+            .iter()
+            .map(|file| fs::read_to_string(file).map_err(|e| e.to_string()))
+            .collect();
+        let _ = fs::remove_dir_all(&pages_dir);
+        pages
+    }
+
+    fn compile_pdf(&self, source: &Path, inputs: &[(&str, &str)]) -> Result<Vec<u8>, String> {
+        let pdf_path = temp_path(source, "pdf");
+        let mut command = Command::new("typst");
+        command.arg("compile").arg(source).arg("--root").arg(".");
+        for (key, value) in inputs {
+            command.arg("--input").arg(format!("{key}={value}"));
+        }
+        let output = command.arg(&pdf_path).output().map_err(|_| {
+            "Typst CLI not found. Install it with 'cargo install typst-cli'".to_string()
+        })?;
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        }
+
+        let pdf = fs::read(&pdf_path).map_err(|e| e.to_string())?;
+        let _ = fs::remove_file(&pdf_path);
+        Ok(pdf)
+    }
+
+    fn compile_png(
+        &self,
+        source: &Path,
+        inputs: &[(&str, &str)],
+        pixels_per_pt: f32,
+    ) -> Result<Vec<u8>, String> {
+        let png_path = temp_path(source, "png");
+        let mut command = Command::new("typst");
+        command.arg("compile").arg(source).arg("--root").arg(".");
+        for (key, value) in inputs {
+            command.arg("--input").arg(format!("{key}={value}"));
+        }
+        // `--ppi` is pixels per inch; a point is 1/72 inch.
+        let output = command
+            .arg("--ppi")
+            .arg((pixels_per_pt * 72.0).to_string())
+            .arg(&png_path)
+            .output()
+            .map_err(|_| {
+                "Typst CLI not found. Install it with 'cargo install typst-cli'".to_string()
+            })?;
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        }
+
+        let png = fs::read(&png_path).map_err(|e| e.to_string())?;
+        let _ = fs::remove_file(&png_path);
+        Ok(png)
+    }
+}
+
+fn temp_path(source: &Path, extension: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "lince-website-{}.{extension}",
+        source.to_string_lossy().replace(['/', '\\'], "__")
+    ))
+}
+
+fn cli_html(source: &Path) -> Result<Compiled, String> {
+    let deps_path = temp_path(source, "deps");
+
+    // The "-" at the end tells typst to output to stdout instead of a file
+    let output = Command::new("typst")
+        .arg("compile")
+        .arg(source)
+        .arg("--root")
+        .arg(".")
+        .arg("--format")
+        .arg("html")
+        .arg("--features")
+        .arg("html")
+        .arg("--deps")
+        .arg(&deps_path)
+        .arg("--deps-format")
+        .arg("zero")
+        .arg("-")
+        .output()
+        .map_err(|_| {
+            "Typst CLI not found. Install it with 'cargo install typst-cli'".to_string()
+        })?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+
+    let deps = crate::cache::read_typst_deps(&deps_path);
+    let _ = fs::remove_file(&deps_path);
+    Ok(Compiled {
+        output: String::from_utf8_lossy(&output.stdout).to_string(),
+        deps,
+    })
+}
+
+fn cli_svg(source: &Path) -> Result<Compiled, String> {
+    let svg_path = temp_path(source, "svg");
+    let output = Command::new("tinymist")
+        .arg("compile")
+        .arg(source)
+        .arg(&svg_path)
+        .arg("--root")
+        .arg(".")
+        .arg("--format")
+        .arg("svg")
+        .output()
+        .map_err(|_| "Tinymist CLI not found".to_string())?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+
+    let svg = fs::read_to_string(&svg_path).map_err(|e| e.to_string())?;
+    let _ = fs::remove_file(&svg_path);
+    Ok(Compiled {
+        output: svg,
+        // Tinymist does not report dependencies; the HTML pass does.
+        deps: Vec::new(),
+    })
+}
+
+/// Compiles with the `typst` crates, sharing one font book, one file cache and
+/// one package store across every post of the build. Packages missing from the
+/// local Typst package directories are downloaded into the cache, as the CLI
+/// does.
+pub struct InProcessBackend {
+    root: PathBuf,
+    library: LazyHash<Library>,
+    fonts: FontStore,
+    packages: SystemPackages,
+    files: Mutex<HashMap<FileId, Bytes>>,
+    sources: Mutex<HashMap<FileId, Source>>,
+}
+
+impl InProcessBackend {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        let mut fonts = FontStore::new();
+        fonts.extend(fonts::embedded());
+        Self {
+            root: root.into(),
+            library: library_with_inputs(&[]),
+            fonts,
+            packages: SystemPackages::new(SystemDownloader::new(concat!(
+                "lince-website/",
+                env!("CARGO_PKG_VERSION")
+            ))),
+            files: Mutex::new(HashMap::new()),
+            sources: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl TypstBackend for InProcessBackend {
+    fn name(&self) -> &'static str {
+        "in-process"
+    }
+
+    fn compile(&self, source: &Path, format: OutputFormat) -> Result<Compiled, String> {
+        match format {
+            OutputFormat::Html => {
+                let (document, deps) = self.document::<HtmlDocument>(source, &self.library)?;
+                let output =
+                    typst_html::html(&document, &HtmlOptions::default()).map_err(messages)?;
+                Ok(Compiled { output, deps })
+            }
+            OutputFormat::Svg => {
+                let (document, deps) = self.document::<PagedDocument>(source, &self.library)?;
+                let options = SvgOptions::default();
+                // Same shape as the CLIs: a single page is exported as is, and
+                // the root carries the `typst-doc` class tinymist still writes.
+                let output = match document.pages() {
+                    [page] => typst_svg::svg(page, &options),
+                    _ => typst_svg::svg_merged(&document, &options, Abs::zero()),
+                }
+                .replacen("<svg ", "<svg class=\"typst-doc\" ", 1);
+                Ok(Compiled { output, deps })
+            }
+        }
+    }
+
+    fn compile_pages(&self, source: &Path, inputs: &[(&str, &str)]) -> Result<Vec<String>, String> {
+        let (document, _) = self.document::<PagedDocument>(source, &library_with_inputs(inputs))?;
+        Ok(document
+            .pages()
+            .iter()
+            .map(|page| typst_svg::svg(page, &SvgOptions::default()))
+            .collect())
+    }
+
+    fn compile_pdf(&self, source: &Path, inputs: &[(&str, &str)]) -> Result<Vec<u8>, String> {
+        let (document, _) = self.document::<PagedDocument>(source, &library_with_inputs(inputs))?;
+        typst_pdf::pdf(&document, &PdfOptions::default()).map_err(messages)
+    }
+
+    fn compile_png(
+        &self,
+        source: &Path,
+        inputs: &[(&str, &str)],
+        pixels_per_pt: f32,
+    ) -> Result<Vec<u8>, String> {
+        let (document, _) = self.document::<PagedDocument>(source, &library_with_inputs(inputs))?;
+        let page = document
+            .pages()
+            .first()
+            .ok_or_else(|| format!("{} has no pages", source.display()))?;
+        let options = RenderOptions {
+            pixel_per_pt: Scalar::new(f64::from(pixels_per_pt)),
+            ..RenderOptions::default()
+        };
+        typst_render::render(page, &options)
+            .encode_png()
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// A standard library with HTML export enabled, whose `sys.inputs` holds
+/// `inputs`.
+fn library_with_inputs(inputs: &[(&str, &str)]) -> LazyHash<Library> {
+    let inputs: Dict = inputs
+        .iter()
+        .map(|(key, value)| (Str::from(*key), Value::Str(Str::from(*value))))
+        .collect();
+    LazyHash::new(
+        Library::builder()
+            .with_inputs(inputs)
+            .with_features([Feature::Html].into_iter().collect())
+            .build(),
+    )
+}
+
+fn messages(errors: impl IntoIterator<Item = SourceDiagnostic>) -> String {
+    errors
+        .into_iter()
+        .map(|e| e.message.to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl InProcessBackend {
+    fn document<D: Output>(
+        &self,
+        source: &Path,
+        library: &LazyHash<Library>,
+    ) -> Result<(D, Vec<PathBuf>), String> {
+        let vpath = VirtualPath::virtualize(&self.root, source)
+            .or_else(|_| VirtualPath::new(source.to_string_lossy()))
+            .map_err(|_| format!("{} is outside the project root", source.display()))?;
+        let world = PostWorld {
+            backend: self,
+            library,
+            main: RootedPath::new(VirtualRoot::Project, vpath).intern(),
+            deps: Mutex::new(HashSet::new()),
+        };
+        let document = typst::compile::<D>(&world).output.map_err(messages)?;
+
+        let mut deps: Vec<PathBuf> = world
+            .deps
+            .into_inner()
+            .unwrap_or_else(|e| e.into_inner())
+            .into_iter()
+            .collect();
+        deps.sort();
+        Ok((document, deps))
+    }
+}
+
+/// The `World` of one compilation, backed by the shared backend state.
+struct PostWorld<'a> {
+    backend: &'a InProcessBackend,
+    library: &'a LazyHash<Library>,
+    main: FileId,
+    deps: Mutex<HashSet<PathBuf>>,
+}
+
+impl PostWorld<'_> {
+    fn path(&self, id: FileId) -> FileResult<PathBuf> {
+        let root = match id.root() {
+            VirtualRoot::Package(spec) => self.backend.packages.obtain(spec)?.path().to_path_buf(),
+            VirtualRoot::Project => self.backend.root.clone(),
+        };
+        Ok(id.vpath().realize(&root)?)
+    }
+
+    fn read(&self, id: FileId) -> FileResult<Bytes> {
+        let path = self.path(id)?;
+        if let Ok(mut deps) = self.deps.lock() {
+            deps.insert(path.clone());
+        }
+        let mut files = self.backend.files.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(bytes) = files.get(&id) {
+            return Ok(bytes.clone());
+        }
+        let bytes = Bytes::new(fs::read(&path).map_err(|e| FileError::from_io(e, &path))?);
+        files.insert(id, bytes.clone());
+        Ok(bytes)
+    }
+}
+
+impl World for PostWorld<'_> {
+    fn library(&self) -> &LazyHash<Library> {
+        self.library
+    }
+
+    fn book(&self) -> &LazyHash<FontBook> {
+        self.backend.fonts.book()
+    }
+
+    /// A missing or unreadable post surfaces as a compile error from
+    /// `source`, so `FallbackBackend` moves on to the CLI.
+    fn main(&self) -> FileId {
+        self.main
+    }
+
+    fn source(&self, id: FileId) -> FileResult<Source> {
+        if let Some(source) = self
+            .backend
+            .sources
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&id)
+        {
+            // Still record the dependency for this post's fingerprint.
+            if let (Ok(path), Ok(mut deps)) = (self.path(id), self.deps.lock()) {
+                deps.insert(path);
+            }
+            return Ok(source.clone());
+        }
+        let bytes = self.read(id)?;
+        let text = std::str::from_utf8(&bytes)?;
+        let source = Source::new(id, text.to_string());
+        self.backend
+            .sources
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(id, source.clone());
+        Ok(source)
+    }
+
+    fn file(&self, id: FileId) -> FileResult<Bytes> {
+        self.read(id)
+    }
+
+    fn font(&self, index: usize) -> Option<Font> {
+        self.backend.fonts.font(index)
+    }
+
+    fn today(&self, offset: Option<Duration>) -> Option<Datetime> {
+        let secs = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs() as i64;
+        let offset = offset.map_or(0, |offset| offset.seconds() as i64);
+        let (year, month, day) = civil_from_days((secs + offset) / 86_400);
+        Datetime::from_ymd(year, month, day)
+    }
+}
+
+/// Days since 1970-01-01 to a proleptic Gregorian `(year, month, day)`.
+pub fn civil_from_days(days: i64) -> (i32, u8, u8) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    let year = (yoe + era * 400 + i64::from(month <= 2)) as i32;
+    (year, month, day)
+}
+
+/// Proleptic Gregorian `(year, month, day)` to days since 1970-01-01; the
+/// inverse of `civil_from_days`.
+pub fn days_from_civil(year: i32, month: u8, day: u8) -> i64 {
+    let year = i64::from(year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let month = i64::from(month);
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Tries each backend in order and returns the first success.
+pub struct FallbackBackend {
+    backends: Vec<Box<dyn TypstBackend>>,
+}
+
+impl FallbackBackend {
+    fn first_ok<T>(
+        &self,
+        compile: impl Fn(&dyn TypstBackend) -> Result<T, String>,
+    ) -> Result<T, String> {
+        let mut errors = Vec::new();
+        for backend in &self.backends {
+            match compile(backend.as_ref()) {
+                Ok(output) => return Ok(output),
+                Err(err) => errors.push(format!("[{}] {}", backend.name(), err.trim())),
+            }
+        }
+        Err(errors.join("\n"))
+    }
+}
+
+impl TypstBackend for FallbackBackend {
+    fn name(&self) -> &'static str {
+        "fallback"
+    }
+
+    fn compile(&self, source: &Path, format: OutputFormat) -> Result<Compiled, String> {
+        self.first_ok(|backend| backend.compile(source, format))
+    }
+
+    fn compile_pages(&self, source: &Path, inputs: &[(&str, &str)]) -> Result<Vec<String>, String> {
+        self.first_ok(|backend| backend.compile_pages(source, inputs))
+    }
+
+    fn compile_pdf(&self, source: &Path, inputs: &[(&str, &str)]) -> Result<Vec<u8>, String> {
+        self.first_ok(|backend| backend.compile_pdf(source, inputs))
+    }
+
+    fn compile_png(
+        &self,
+        source: &Path,
+        inputs: &[(&str, &str)],
+        pixels_per_pt: f32,
+    ) -> Result<Vec<u8>, String> {
+        self.first_ok(|backend| backend.compile_png(source, inputs, pixels_per_pt))
+    }
+}
+
+/// In-process compilation first, then the CLIs. Shared by the whole build so
+/// fonts are loaded and files are read only once.
+pub fn default_backend() -> &'static FallbackBackend {
+    static BACKEND: OnceLock<FallbackBackend> = OnceLock::new();
+    BACKEND.get_or_init(|| FallbackBackend {
+        backends: vec![Box::new(InProcessBackend::new(".")), Box::new(CliBackend)],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CliBackend, InProcessBackend, OutputFormat, TypstBackend, civil_from_days, days_from_civil,
+    };
+    use crate::{
+        pages::blog::{PostKind, extract_body, get_blog_posts},
+        sidecar::{attr, start_tag_end},
+    };
+    use std::{fs, path::Path};
+
+    const FIXTURE: &str = "#set page(width: 200pt, height: auto)\n= Parity\n\nSame text, both backends. #image(\"dot.svg\", width: 10pt)\n";
+
+    fn root_attr(svg: &str, name: &str) -> Option<String> {
+        let start = svg.find("<svg")?;
+        let tag = &svg[start..start_tag_end(svg, start)?];
+        attr(tag, name).map(str::to_string)
+    }
+
+    #[test]
+    fn dates_are_converted_from_unix_days() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(20_745), (2026, 10, 19));
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2026, 10, 19), 20_745);
+        assert_eq!(days_from_civil(2024, 2, 29), 19_782);
+    }
+
+    #[test]
+    fn in_process_backend_matches_the_cli() {
+        let root = std::env::temp_dir().join("lince-website-compiler-test");
+        fs::create_dir_all(&root).unwrap();
+        let source = root.join("post.typ");
+        fs::write(&source, FIXTURE).unwrap();
+        fs::write(
+            root.join("dot.svg"),
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="1" height="1"/>"#,
+        )
+        .unwrap();
+
+        let backend = InProcessBackend::new(&root);
+        let in_process = backend
+            .compile(&source, OutputFormat::Svg)
+            .unwrap_or_else(|e| panic!("In-process compilation failed: {e}"));
+        assert!(in_process.output.contains("<svg class=\"typst-doc\""));
+        assert!(
+            in_process.deps.iter().any(|d| d.ends_with("dot.svg")),
+            "Dependencies must include images read by the document"
+        );
+        let html = backend
+            .compile(&source, OutputFormat::Html)
+            .unwrap_or_else(|e| panic!("In-process HTML export failed: {e}"));
+        assert!(html.output.contains("Same text, both backends."));
+        assert!(
+            backend
+                .compile(&root.join("missing.typ"), OutputFormat::Svg)
+                .is_err(),
+            "A missing post must fail so the CLI gets a chance"
+        );
+        let pdf = backend
+            .compile_pdf(&source, &[])
+            .unwrap_or_else(|e| panic!("In-process PDF export failed: {e}"));
+        assert!(pdf.starts_with(b"%PDF"));
+    }
+
+    #[test]
+    #[ignore = "needs the typst and tinymist CLIs"]
+    fn fixture_renders_the_same_with_both_backends() {
+        let root = std::env::temp_dir().join("lince-website-compiler-parity-test");
+        fs::create_dir_all(&root).unwrap();
+        let source = root.join("post.typ");
+        fs::write(&source, FIXTURE).unwrap();
+        fs::write(
+            root.join("dot.svg"),
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="1" height="1"/>"#,
+        )
+        .unwrap();
+
+        let in_process = InProcessBackend::new(&root)
+            .compile(&source, OutputFormat::Svg)
+            .unwrap_or_else(|e| panic!("In-process compilation failed: {e}"));
+        let cli = CliBackend
+            .compile(&source, OutputFormat::Svg)
+            .unwrap_or_else(|e| {
+                panic!("CLI compilation failed (are typst and tinymist installed?): {e}")
+            });
+        assert_eq!(
+            root_attr(&in_process.output, "viewBox"),
+            root_attr(&cli.output, "viewBox"),
+            "Backends disagree on the document size"
+        );
+    }
+
+    /// Visible text of an HTML body, with whitespace collapsed.
+    fn text_content(html: &str) -> String {
+        let mut text = String::new();
+        let mut in_tag = false;
+        for c in html.chars() {
+            match c {
+                '<' => in_tag = true,
+                '>' => {
+                    in_tag = false;
+                    text.push(' ');
+                }
+                _ if !in_tag => text.push(c),
+                _ => {}
+            }
+        }
+        text.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    #[test]
+    #[ignore = "needs the typst and tinymist CLIs"]
+    fn blog_posts_render_the_same_with_both_backends() {
+        let in_process = InProcessBackend::new(".");
+        // Years in review are generated, not compiled from a source.
+        let posts = get_blog_posts()
+            .into_iter()
+            .filter(|post| post.kind == PostKind::Typst);
+        for post in posts {
+            let source = format!("content/blog/posts/{}.typ", post.slug);
+            let source = Path::new(&source);
+            let compile = |backend: &dyn TypstBackend| -> Result<_, String> {
+                let svg = backend.compile(source, OutputFormat::Svg)?.output;
+                let pages = backend.compile_pages(source, &[])?.len();
+                let html = backend.compile(source, OutputFormat::Html)?.output;
+                Ok((svg, pages, text_content(&extract_body(html))))
+            };
+            let ours =
+                compile(&in_process).unwrap_or_else(|e| panic!("[in-process] {}: {e}", post.slug));
+            let cli = compile(&CliBackend).unwrap_or_else(|e| panic!("[cli] {}: {e}", post.slug));
+            assert_eq!(
+                root_attr(&ours.0, "viewBox"),
+                root_attr(&cli.0, "viewBox"),
+                "Backends disagree on the size of {}",
+                post.slug
+            );
+            assert_eq!(
+                ours.1, cli.1,
+                "Backends disagree on the pages of {}",
+                post.slug
+            );
+            assert_eq!(
+                ours.2, cli.2,
+                "Backends disagree on the text of {}",
+                post.slug
+            );
+        }
+    }
+}
@@ -1 +1,339 @@
-pub const INCLUDE_BLOG: bool = true;
+//! Deployment settings, read from `site.toml` at startup so a fork can publish
+//! its own instance by editing that file instead of the code.
+
+use crate::i18n::get_translations;
+use serde::Deserialize;
+use std::fs;
+
+const SITE_CONFIG_PATH: &str = "site.toml";
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SiteConfig {
+    /// Canonical origin the site is deployed at, for links that leave the
+    /// site (newsletters, share cards, announcements, the install command).
+    pub base_url: String,
+    /// Path the site is served under (`/website`, `/preview/pr-42`), or empty
+    /// when it is served from the root of `base_url`. Every internal link is
+    /// built with `href`, which prepends it.
+    #[serde(default)]
+    pub base_path: String,
+    #[serde(default)]
+    pub url_scheme: UrlScheme,
+    /// GitHub `owner/name` of the application: source, releases, discussions
+    /// and license links.
+    pub repository: String,
+    /// GitHub `owner/name` of this website's source.
+    pub website_repository: String,
+    /// `lang_code`s to build. `en` is required: it is the source of truth and
+    /// the unsuffixed pages.
+    pub languages: Vec<String>,
+    #[serde(default)]
+    pub sections: Sections,
+    pub links: Links,
+}
+
+/// How the pages of a `Route` are laid out under `output/`.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Hash, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum UrlScheme {
+    /// `blog/2026/2026_04_tmil.pt-br.html`
+    #[default]
+    Suffix,
+    /// `pt-br/blog/2026/04/tmil/index.html`, linked as `/pt-br/blog/2026/04/tmil/`
+    Directories,
+}
+
+/// Optional parts of the site; both are on unless `site.toml` says otherwise.
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Sections {
+    /// Blog posts, series, author pages, reviews and announcements.
+    pub blog: bool,
+    /// The roadmap page, built from the TMIL posts.
+    pub roadmap: bool,
+}
+
+impl Default for Sections {
+    fn default() -> Self {
+        Self {
+            blog: true,
+            roadmap: true,
+        }
+    }
+}
+
+/// External pages that are not on the application's repository.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Links {
+    /// Documentation PDF, dark mode.
+    pub documentation: String,
+    /// The same documentation in light mode.
+    pub documentation_light: String,
+    pub youtube: String,
+    pub matrix: String,
+    pub discord: String,
+    pub instagram: String,
+}
+
+/// An external link target that depends on the deployment.
+#[derive(Clone, Copy, Debug)]
+pub enum SiteLink {
+    Source,
+    WebsiteSource,
+    Releases,
+    Discussions,
+    License,
+    Documentation,
+    DocumentationLight,
+    YouTube,
+    Matrix,
+    Discord,
+    Instagram,
+}
+
+fn is_http_url(url: &str) -> bool {
+    url.strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .is_some_and(|rest| !rest.is_empty() && !rest.starts_with('/'))
+}
+
+/// Empty, or `/`-separated segments that need no escaping in a URL.
+fn is_base_path(path: &str) -> bool {
+    path.is_empty()
+        || path.strip_prefix('/').is_some_and(|rest| {
+            rest.split('/').all(|segment| {
+                !segment.is_empty()
+                    && segment != ".."
+                    && segment
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '~'))
+            })
+        })
+}
+
+fn is_repository_slug(slug: &str) -> bool {
+    slug.split_once('/').is_some_and(|(owner, name)| {
+        [owner, name].iter().all(|part| {
+            !part.is_empty()
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        })
+    })
+}
+
+impl SiteConfig {
+    /// Read and validate `site.toml`.
+    pub fn load() -> Result<Self, String> {
+        let raw = fs::read_to_string(SITE_CONFIG_PATH)
+            .map_err(|e| format!("Failed to read {SITE_CONFIG_PATH}: {e}"))?;
+        Self::parse(&raw).map_err(|e| format!("{SITE_CONFIG_PATH}: {e}"))
+    }
+
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let mut config: Self = toml::from_str(raw).map_err(|e| e.to_string())?;
+        config.base_url = config.base_url.trim_end_matches('/').to_string();
+        config.base_path = config.base_path.trim_end_matches('/').to_string();
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if !is_http_url(&self.base_url) || self.host().contains('/') {
+            return Err(format!(
+                "`base_url` must be an http(s) origin without a path (see `base_path`), got `{}`",
+                self.base_url
+            ));
+        }
+        if !is_base_path(&self.base_path) {
+            return Err(format!(
+                "`base_path` must be empty or like `/website`, got `{}`",
+                self.base_path
+            ));
+        }
+        for (key, slug) in [
+            ("repository", &self.repository),
+            ("website_repository", &self.website_repository),
+        ] {
+            if !is_repository_slug(slug) {
+                return Err(format!(
+                    "`{key}` must be a GitHub `owner/name`, got `{slug}`"
+                ));
+            }
+        }
+
+        let translations = get_translations();
+        let mut known: Vec<&str> = translations.keys().copied().collect();
+        known.sort();
+        for (i, lang_code) in self.languages.iter().enumerate() {
+            if !known.contains(&lang_code.as_str()) {
+                return Err(format!(
+                    "`languages` lists `{lang_code}`, which has no translations (known: {})",
+                    known.join(", ")
+                ));
+            }
+            if self.languages[..i].contains(lang_code) {
+                return Err(format!("`languages` lists `{lang_code}` twice"));
+            }
+        }
+        if !self.has_language("en") {
+            return Err("`languages` must include `en`".to_string());
+        }
+
+        if self.sections.roadmap && !self.sections.blog {
+            return Err(
+                "`sections.roadmap` needs `sections.blog`: it is built from the TMIL posts"
+                    .to_string(),
+            );
+        }
+
+        let Links {
+            documentation,
+            documentation_light,
+            youtube,
+            matrix,
+            discord,
+            instagram,
+        } = &self.links;
+        for (key, url) in [
+            ("documentation", documentation),
+            ("documentation_light", documentation_light),
+            ("youtube", youtube),
+            ("matrix", matrix),
+            ("discord", discord),
+            ("instagram", instagram),
+        ] {
+            if !is_http_url(url) {
+                return Err(format!("`links.{key}` must be an http(s) URL, got `{url}`"));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn has_language(&self, lang_code: &str) -> bool {
+        self.languages.iter().any(|code| code == lang_code)
+    }
+
+    /// Href of a site path (`/blog.html`, `/assets/style.css`) wherever the
+    /// site is served from.
+    pub fn href(&self, path: &str) -> String {
+        format!("{}/{}", self.base_path, path.trim_start_matches('/'))
+    }
+
+    /// Absolute URL of a site path, for links that leave the site.
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, self.href(path))
+    }
+
+    /// `base_url` without its scheme, for display.
+    pub fn host(&self) -> &str {
+        self.base_url
+            .split_once("://")
+            .map_or(self.base_url.as_str(), |(_, host)| host)
+    }
+
+    fn repository_url(&self) -> String {
+        format!("https://github.com/{}", self.repository)
+    }
+
+    /// Download URL of a release asset of the latest application release.
+    pub fn download_url(&self, asset: &str) -> String {
+        format!("{}/releases/latest/download/{asset}", self.repository_url())
+    }
+
+    /// Shell one-liner that runs the published `install.sh`.
+    pub fn install_command(&self) -> String {
+        format!("curl -fsSL {} | bash", self.url("/install.sh"))
+    }
+
+    pub fn link(&self, link: SiteLink) -> String {
+        match link {
+            SiteLink::Source => self.repository_url(),
+            SiteLink::WebsiteSource => format!("https://github.com/{}", self.website_repository),
+            SiteLink::Releases => format!("{}/releases/latest", self.repository_url()),
+            SiteLink::Discussions => format!("{}/discussions", self.repository_url()),
+            SiteLink::License => format!("{}/blob/main/LICENSE", self.repository_url()),
+            SiteLink::Documentation => self.links.documentation.clone(),
+            SiteLink::DocumentationLight => self.links.documentation_light.clone(),
+            SiteLink::YouTube => self.links.youtube.clone(),
+            SiteLink::Matrix => self.links.matrix.clone(),
+            SiteLink::Discord => self.links.discord.clone(),
+            SiteLink::Instagram => self.links.instagram.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SITE_CONFIG_PATH, SiteConfig, SiteLink};
+    use crate::i18n::get_translations;
+
+    #[test]
+    fn site_toml_is_valid_and_invalid_configs_are_rejected() {
+        let site = SiteConfig::load().unwrap();
+        assert_eq!(
+            site.link(SiteLink::Releases),
+            "https://github.com/lince-social/lince/releases/latest"
+        );
+        assert_eq!(
+            site.install_command(),
+            "curl -fsSL https://lince.social/install.sh | bash"
+        );
+
+        let raw = std::fs::read_to_string(SITE_CONFIG_PATH).unwrap();
+        let nested = SiteConfig::parse(&raw.replacen(
+            "base_path = \"\"",
+            "base_path = \"/preview/pr-42/\"",
+            1,
+        ))
+        .unwrap();
+        assert_eq!(nested.href("/blog.html"), "/preview/pr-42/blog.html");
+        assert_eq!(
+            nested.url("/assets/og/index.png"),
+            "https://lince.social/preview/pr-42/assets/og/index.png"
+        );
+        assert_eq!(site.href("/blog.html"), "/blog.html");
+
+        let broken = |from: &str, to: &str| {
+            assert!(raw.contains(from), "site.toml has no `{from}`");
+            SiteConfig::parse(&raw.replacen(from, to, 1)).unwrap_err()
+        };
+        assert!(broken("base_url = \"https://", "base_url = \"ftp://").contains("`base_url`"));
+        assert!(broken("lince.social\"", "lince.social/website\"").contains("`base_path`"));
+        assert!(
+            broken("scheme = \"suffix\"", "scheme = \"pretty\"")
+                .contains("unknown variant `pretty`")
+        );
+        assert!(broken("\"lince-social/lince\"", "\"lince\"").contains("`repository`"));
+        assert!(broken("\"pt-br\"", "\"fr\"").contains("`fr`, which has no translations"));
+        assert!(broken("\"en\", ", "").contains("must include `en`"));
+        assert!(broken("blog = true", "blog = false").contains("`sections.roadmap`"));
+        assert!(broken("youtube = \"", "youtube = \"www.").contains("`links.youtube`"));
+        assert!(broken("[links]", "typo = 1\n[links]").contains("typo"));
+    }
+
+    #[test]
+    fn index_links_come_from_site_toml() {
+        let raw = std::fs::read_to_string(SITE_CONFIG_PATH).unwrap();
+        let fork = SiteConfig::parse(
+            &raw.replace("lince-social/lince/main", "fork/lince/main")
+                .replacen("3Gr9rYWHpu", "fork", 1),
+        )
+        .unwrap();
+        for t in get_translations().values() {
+            let body = t.index_content[0].body(&fork);
+            for link in [
+                SiteLink::Matrix,
+                SiteLink::Discord,
+                SiteLink::Documentation,
+                SiteLink::DocumentationLight,
+            ] {
+                let href = format!(r#"href="{}""#, fork.link(link));
+                assert!(body.contains(&href), "{}: no {href}", t.lang_code);
+            }
+            assert!(!body.contains("lince-social/lince"), "{body}");
+        }
+    }
+}
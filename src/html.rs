@@ -1,27 +1,196 @@
-use crate::config::INCLUDE_BLOG;
-use crate::i18n::{GITHUB_LATEST_RELEASE_URL, Translations, YOUTUBE_URL};
+use crate::config::{SiteConfig, SiteLink};
+use crate::i18n::Translations;
+use crate::pages::feed::feed_path;
+use crate::routes::{NavItem, Route};
+use crate::share_card::ShareCard;
+use crate::share_card::{SHARE_CARD_HEIGHT, SHARE_CARD_WIDTH};
 use maud::{DOCTYPE, PreEscaped, html};
+use std::{collections::HashSet, fs, path::Path};
 
-fn lang_suffix(lang: &str) -> &str {
-    if lang == "en" {
-        ""
-    } else if lang == "pt-br" {
-        ".pt-br"
-    } else {
-        ".zh"
+/// Name of the site, shown after each page's title.
+const SITE_NAME: &str = "Lince";
+
+/// A generated page: its path under `output/` and its HTML.
+pub type OutputPage = (String, String);
+
+/// Renders the share card of a page: `share_card`, or a stub in tests.
+pub type CardRenderer<'a> = &'a dyn Fn(&str, &ShareCard) -> Option<String>;
+
+/// Write generated pages under `output/`, unless two of them share a path:
+/// `Route::path` can map different slugs to one directory (`2026/2026_04_tmil`
+/// and `2026/04_tmil`), and one would silently replace the other.
+pub fn write_pages(pages: Vec<OutputPage>) -> Result<(), String> {
+    let mut paths = HashSet::new();
+    if let Some((path, _)) = pages.iter().find(|(path, _)| !paths.insert(path)) {
+        return Err(format!("Two pages are generated at output/{path}"));
     }
+    for (path, html) in pages {
+        let output_path = Path::new("output").join(path);
+        if let Some(parent) = output_path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        fs::write(&output_path, html)
+            .unwrap_or_else(|e| panic!("Failed to write {}: {e}", output_path.display()));
+    }
+    Ok(())
 }
 
-pub fn page(body: &str, t: &Translations, current_page: &str, show_home: bool) -> String {
-    let suffix = lang_suffix(t.lang_code);
+/// Values of the attributes of `html` that point somewhere, with `srcset`
+/// split into its URLs.
+fn links(html: &str) -> Vec<&str> {
+    let mut links = Vec::new();
+    for (i, _) in html.match_indices("=\"") {
+        let name = html[..i]
+            .rsplit(|c: char| c.is_whitespace() || c == '<')
+            .next()
+            .unwrap_or_default();
+        let is_link = matches!(name, "href" | "src" | "srcset" | "xlink:href")
+            || (name.starts_with("data-") && name.ends_with("-href"));
+        if !is_link {
+            continue;
+        }
+        let value = &html[i + 2..];
+        let value = &value[..value.find('"').unwrap_or(value.len())];
+        match name {
+            "srcset" => links.extend(
+                value
+                    .split(',')
+                    .filter_map(|candidate| candidate.split_whitespace().next()),
+            ),
+            _ => links.push(value),
+        }
+    }
+    links
+}
 
-    // Prepare language suffixes and page links so generated pages point
-    // to the actual files produced by `main.rs` (e.g. `index.pt-br.html`).
-    let home_href = format!("/index{}.html", suffix);
-    let blog_href = format!("/blog{}.html", suffix);
-    let link_en = format!("/{}{}.html", current_page, "");
-    let link_pt = format!("/{}{}.html", current_page, ".pt-br");
-    let link_zh = format!("/{}{}.html", current_page, ".zh");
+/// Check that every internal link of `pages` is under `base_path` and points
+/// at one of `pages`, so a link to a route that is not built fails the build.
+/// Only links to other files (images, PDFs, `style.css`) may point at a file
+/// already in `output/`; a page left there by an earlier build does not count.
+pub fn check_links(site: &SiteConfig, pages: &[OutputPage]) -> Result<(), String> {
+    let generated: HashSet<&str> = pages.iter().map(|(path, _)| path.as_str()).collect();
+    let mut broken = Vec::new();
+    for (path, html) in pages {
+        for link in links(html) {
+            if link.is_empty()
+                || link.starts_with('#')
+                || ["https://", "http://", "mailto:", "data:"]
+                    .iter()
+                    .any(|scheme| link.starts_with(scheme))
+            {
+                continue;
+            }
+            let Some(target) = link
+                .strip_prefix(site.base_path.as_str())
+                .and_then(|rest| rest.strip_prefix('/'))
+            else {
+                broken.push(format!("{path} links to `{link}`, outside `base_path`"));
+                continue;
+            };
+            let target = target.split(['#', '?']).next().unwrap_or_default();
+            let index;
+            let target = match target.is_empty() || target.ends_with('/') {
+                true => {
+                    index = format!("{target}index.html");
+                    index.as_str()
+                }
+                false => target,
+            };
+            let is_page = target.ends_with(".html");
+            if !generated.contains(target)
+                && (is_page || !Path::new("output").join(target).is_file())
+            {
+                broken.push(format!("{path} links to `{link}`, which is not built"));
+            }
+        }
+    }
+    match broken.is_empty() {
+        true => Ok(()),
+        false => Err(format!("Broken internal links:\n{}", broken.join("\n"))),
+    }
+}
+
+/// A page that sends browsers and crawlers to `href`, for hosts without
+/// server-side redirects.
+pub fn redirect_page(href: &str, canonical_url: &str) -> String {
+    html! {
+        (DOCTYPE)
+        html {
+            head {
+                meta charset="UTF-8";
+                meta name="robots" content="noindex";
+                link rel="canonical" href=(canonical_url);
+                meta http-equiv="refresh" content=(format!("0; url={href}"));
+                title { "Lince" }
+            }
+            body {
+                a href=(href) { (href) }
+            }
+        }
+    }
+    .into_string()
+}
+
+/// The page of `route` in `lang_code` and, when `site` uses another URL
+/// scheme than the default, a redirect from its default path so links to
+/// the old files keep working.
+pub fn route_pages(
+    site: &SiteConfig,
+    route: Route,
+    lang_code: &str,
+    html: String,
+) -> Vec<OutputPage> {
+    let path = route.path(site, lang_code);
+    let old_path = route.suffix_path(lang_code);
+    let mut pages = Vec::new();
+    if old_path != path {
+        let stub = redirect_page(&route.href(site, lang_code), &route.url(site, lang_code));
+        pages.push((old_path, stub));
+    }
+    pages.push((path, html));
+    pages
+}
+
+/// Short label of the language switcher button and name in its dropdown.
+fn language_labels(lang_code: &str) -> (&'static str, &'static str) {
+    match lang_code {
+        "en" => ("EN", "English"),
+        "pt-br" => ("PT", "Português"),
+        _ => ("中文", "中文"),
+    }
+}
+
+/// `title` names the page in its `<title>` and in link previews;
+/// `share_image` is the public path of the page's Open Graph card, if it has one.
+pub fn page(
+    body: &str,
+    t: &Translations,
+    site: &SiteConfig,
+    route: Route,
+    title: &str,
+    show_home: bool,
+    share_image: Option<&str>,
+) -> String {
+    let home_href = Route::Index.href(site, t.lang_code);
+    let blog_href = Route::Blog.href(site, t.lang_code);
+    let nav_class = |item: NavItem| match route.nav_item() == Some(item) {
+        true => "navbar-item active",
+        false => "navbar-item",
+    };
+    let language_links: Vec<(&str, String)> = site
+        .languages
+        .iter()
+        .map(|lang_code| (language_labels(lang_code).1, route.href(site, lang_code)))
+        .collect();
+
+    let full_title = match title {
+        SITE_NAME => SITE_NAME.to_string(),
+        _ => format!("{title} | {SITE_NAME}"),
+    };
+    let og_type = match route {
+        Route::BlogPost(_) => "article",
+        _ => "website",
+    };
 
     html! {
             (DOCTYPE)
@@ -31,62 +200,73 @@ pub fn page(body: &str, t: &Translations, current_page: &str, show_home: bool) -
                 meta name="viewport" content="width=device-width, initial-scale=1.0";
                 meta http-equiv="X-UA-Compatible" content="ie=edge";
                 meta name="description" content="Lince - Registry, Interconnection, and Automation of Needs and Contributions";
-                link rel="icon" href="/assets/black_in_white.ico" type="image/x-icon";
+                link rel="icon" href=(site.href("/assets/black_in_white.ico")) type="image/x-icon";
+                @if site.sections.blog {
+                    link rel="alternate" type="application/rss+xml" title=(t.blog_title) href=(site.href(&feed_path(t.lang_code)));
+                }
+                meta property="og:site_name" content=(SITE_NAME);
+                meta property="og:title" content=(title);
+                meta property="og:type" content=(og_type);
+                meta property="og:url" content=(route.url(site, t.lang_code));
+                @if let Some(image) = share_image {
+                    meta property="og:image" content=(site.url(image));
+                    meta property="og:image:width" content=(SHARE_CARD_WIDTH);
+                    meta property="og:image:height" content=(SHARE_CARD_HEIGHT);
+                    meta name="twitter:card" content="summary_large_image";
+                }
                 script {
-                    (PreEscaped(r#"(function(){try{const s=localStorage.getItem('theme');if(s)document.documentElement.setAttribute('data-theme',s);else document.documentElement.setAttribute('data-theme','dark');}catch(e){} })();"#))
+                    (PreEscaped(r#"(function(){try{const s=localStorage.getItem('theme');if(s)document.documentElement.setAttribute('data-theme',s);else document.documentElement.setAttribute('data-theme','dark');const v=localStorage.getItem('post-view');document.documentElement.setAttribute('data-post-view',v==='text'?'text':'faithful');}catch(e){} })();"#))
                 }
-                link rel="stylesheet" href="/assets/style.css";
-                title { "Lince" }
+                link rel="stylesheet" href=(site.href("/assets/style.css"));
+                title { (full_title) }
             }
             body {
                 nav.navbar {
                     .navbar-container {
                         a.navbar-brand href=(home_href) {
-                            img src="/assets/logo/white.svg" alt="Lince Logo";
+                            img src=(site.href("/assets/logo/white.svg")) alt="Lince Logo";
                             "Lince"
                         }
                         ul.navbar-menu {
                             @if show_home {
                                 li {
-                                    a class=(if current_page == "index" { "navbar-item active" } else { "navbar-item" })
+                                    a class=(nav_class(NavItem::Home))
                                         href=(home_href.clone()) {
                                         (t.nav_home)
                                     }
                                 }
                             }
-                            @if INCLUDE_BLOG {
+                            @if site.sections.blog {
                                 li {
-                                    a class=(if current_page.starts_with("blog") || current_page == "blog" { "navbar-item active" } else { "navbar-item" })
+                                    a class=(nav_class(NavItem::Blog))
                                         href=(blog_href.clone()) {
                                         (t.nav_blog)
                                     }
                                 }
                             }
                             li.desktop-only {
-                                a.navbar-item href="https://github.com/lince-social/lince" {
+                                a.navbar-item href=(site.link(SiteLink::Source)) {
                                     (t.nav_github)
                                 }
                             }
                             li.desktop-only {
-                                a.navbar-item href=(GITHUB_LATEST_RELEASE_URL) {
+                                a.navbar-item href=(site.link(SiteLink::Releases)) {
                                     (t.nav_download)
                                 }
                             }
                             li.desktop-only {
-                                a.navbar-item href=(YOUTUBE_URL) {
+                                a.navbar-item href=(site.link(SiteLink::YouTube)) {
                                     (t.nav_youtube)
                                 }
                             }
                             li.lang-switcher {
                                 button.lang-btn onclick="toggleLangDropdown()" {
-                                    @if t.lang_code == "en" { "EN" }
-                                    @else if t.lang_code == "pt-br" { "PT" }
-                                    @else { "中文" }
+                                    (language_labels(t.lang_code).0)
                                 }
                                 .lang-dropdown id="langDropdown" {
-                                    a.lang-option href=(link_en) { "English" }
-                                    a.lang-option href=(link_pt) { "Português" }
-                                    a.lang-option href=(link_zh) { "中文" }
+                                    @for (name, href) in &language_links {
+                                        a.lang-option href=(href) { (name) }
+                                    }
                                 }
                             }
                             li {
@@ -105,14 +285,7 @@ pub fn page(body: &str, t: &Translations, current_page: &str, show_home: bool) -
                                 h4 { (section.title) }
                                 ul.footer-links {
                                     @for link in &section.links {
-                                        @let href = if link.href.starts_with("http") {
-                                            link.href.to_string()
-                                        } else if link.href.starts_with("/") {
-                                            link.href.to_string()
-                                        } else {
-                                            format!("/{}", link.href)
-                                        };
-                                        li { a href=(href) { (link.text) } }
+                                        li { a href=(link.href.resolve(site, t.lang_code)) { (link.text) } }
                                     }
                                 }
                             }
@@ -180,6 +353,19 @@ pub fn page(body: &str, t: &Translations, current_page: &str, show_home: bool) -
                         if (!button) return;
                         copyHeroInstall(button);
                     }
+                    function loadVideoEmbed(facade) {
+                        const id = facade.dataset.videoId;
+                        if (!id) return true;
+                        const iframe = document.createElement('iframe');
+                        iframe.className = 'video-embed__player';
+                        iframe.src = 'https://www.youtube-nocookie.com/embed/' + encodeURIComponent(id) + '?autoplay=1';
+                        iframe.title = facade.dataset.videoTitle || '';
+                        iframe.allow = 'accelerometer; autoplay; clipboard-write; encrypted-media; gyroscope; picture-in-picture';
+                        iframe.referrerPolicy = 'strict-origin-when-cross-origin';
+                        iframe.allowFullscreen = true;
+                        facade.replaceWith(iframe);
+                        return false;
+                    }
                     function toggleTheme() {
                         const html = document.documentElement;
                         const current = html.getAttribute('data-theme');
@@ -187,12 +373,24 @@ pub fn page(body: &str, t: &Translations, current_page: &str, show_home: bool) -
                         html.setAttribute('data-theme', next);
                         localStorage.setItem('theme', next);
                     }
+                    function setPostView(view) {
+                        document.documentElement.setAttribute('data-post-view', view);
+                        localStorage.setItem('post-view', view);
+                        syncPostViewToggle();
+                    }
+                    function syncPostViewToggle() {
+                        const view = document.documentElement.getAttribute('data-post-view');
+                        document.querySelectorAll('.blog-view-toggle__option').forEach(function(option) {
+                            option.setAttribute('aria-pressed', String(option.dataset.postView === view));
+                        });
+                    }
                     (function() {
                         const saved = localStorage.getItem('theme');
                         if (saved) {
                             document.documentElement.setAttribute('data-theme', saved);
                         }
                         configureHeroDownloadLink();
+                        syncPostViewToggle();
                     })();
                     function toggleLangDropdown() {
                         document.getElementById('langDropdown').classList.toggle('show');
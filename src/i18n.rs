@@ -1,14 +1,7 @@
+use crate::config::{SiteConfig, SiteLink};
+use crate::routes::Route;
 use std::collections::HashMap;
 
-pub const YOUTUBE_URL: &str = "https://www.youtube.com/@lince.social";
-pub const GITHUB_LATEST_RELEASE_URL: &str = "https://github.com/lince-social/lince/releases/latest";
-pub const INSTINTO_URL: &str = "https://raw.githubusercontent.com/lince-social/lince/main/documentation/lince-documentation-dark.pdf";
-pub const LATEST_LINUX_DOWNLOAD_URL: &str =
-    "https://github.com/lince-social/lince/releases/latest/download/lince-x86_64-unknown-linux-gnu";
-pub const LATEST_MACOS_DOWNLOAD_URL: &str =
-    "https://github.com/lince-social/lince/releases/latest/download/lince-aarch64-apple-darwin";
-pub const LATEST_WINDOWS_DOWNLOAD_URL: &str = "https://github.com/lince-social/lince/releases/latest/download/lince-x86_64-pc-windows-msvc.exe";
-
 /// Image configuration for content blocks
 #[derive(Clone, Default)]
 pub struct ContentImage {
@@ -24,6 +17,8 @@ pub struct ContentImage {
 #[derive(Clone)]
 pub struct ContentBlock {
     pub title: &'static str,
+    /// HTML, with `{matrix}`, `{discord}`, `{documentation}` and
+    /// `{documentation_light}` placeholders for the links of `site.toml`
     pub text: &'static str,
     /// Optional image - when present, text wraps on left, image on right
     pub image: Option<ContentImage>,
@@ -38,6 +33,18 @@ impl ContentBlock {
         }
     }
 
+    /// `text` with its link placeholders filled in from `site`
+    pub fn body(&self, site: &SiteConfig) -> String {
+        self.text
+            .replace("{matrix}", &site.link(SiteLink::Matrix))
+            .replace("{discord}", &site.link(SiteLink::Discord))
+            .replace(
+                "{documentation_light}",
+                &site.link(SiteLink::DocumentationLight),
+            )
+            .replace("{documentation}", &site.link(SiteLink::Documentation))
+    }
+
     #[allow(dead_code)]
     pub const fn with_image(
         title: &'static str,
@@ -54,26 +61,52 @@ impl ContentBlock {
     }
 }
 
+/// Where a `LinkItem` points
+#[derive(Clone)]
+pub enum LinkHref {
+    /// A page of this site, in the language of the page linking to it
+    Page(Route<'static>),
+    /// A link that depends on the deployment; see `site.toml`
+    Site(SiteLink),
+}
+
+impl LinkHref {
+    pub fn resolve(&self, site: &SiteConfig, lang_code: &str) -> String {
+        match self {
+            LinkHref::Page(route) => route.href(site, lang_code),
+            LinkHref::Site(link) => site.link(*link),
+        }
+    }
+}
+
 /// A link item for navigation, footer, or quick links sections
 #[derive(Clone)]
 pub struct LinkItem {
-    pub href: &'static str,
+    pub href: LinkHref,
     pub text: &'static str,
     /// Optional CSS class (e.g., "btn btn-primary")
     pub class: &'static str,
 }
 
 impl LinkItem {
-    pub const fn new(href: &'static str, text: &'static str) -> Self {
+    pub const fn new(route: Route<'static>, text: &'static str) -> Self {
         Self {
-            href,
+            href: LinkHref::Page(route),
             text,
             class: "",
         }
     }
 
-    pub const fn with_class(href: &'static str, text: &'static str, class: &'static str) -> Self {
-        Self { href, text, class }
+    pub const fn site(link: SiteLink, text: &'static str) -> Self {
+        Self {
+            href: LinkHref::Site(link),
+            text,
+            class: "",
+        }
+    }
+
+    pub const fn with_class(self, class: &'static str) -> Self {
+        Self { class, ..self }
     }
 }
 
@@ -84,6 +117,14 @@ pub struct LinkGroup {
     pub links: Vec<LinkItem>,
 }
 
+/// Localized copy for one logo variant on the visual identity page
+#[derive(Clone)]
+pub struct IdentityText {
+    pub title: &'static str,
+    pub description: Option<&'static str>,
+    pub alt: &'static str,
+}
+
 /// Translation structure for easy AI agent updates
 /// To update translations:
 /// 1. Modify the English (en) text as the source of truth
@@ -127,6 +168,98 @@ pub struct Translations {
     pub blog_title: &'static str,
     pub blog_back_to_posts: &'static str,
     pub blog_watch_video: &'static str,
+    /// Link from a TMIL post to its slide deck
+    pub blog_slides: &'static str,
+    /// Button of the slide deck viewer that enters fullscreen
+    pub slides_fullscreen: &'static str,
+    /// Link from a post to its PDF edition
+    pub blog_pdf: &'static str,
+    pub blog_no_posts: &'static str,
+    pub blog_older: &'static str,
+    pub blog_newer: &'static str,
+    /// Localized prefix replacing "This Month in Lince" in TMIL post titles
+    pub blog_tmil_title: &'static str,
+    pub blog_series_previous: &'static str,
+    pub blog_series_next: &'static str,
+    pub blog_series_label: &'static str,
+    pub blog_by: &'static str,
+    pub blog_author_contact: &'static str,
+    pub blog_author_posts: &'static str,
+    pub blog_video_play: &'static str,
+    /// Shown under the video facade; the player only loads after a click
+    pub blog_video_consent: &'static str,
+    /// Label of the toggle between the faithful (SVG) and text (HTML) post views
+    pub blog_view_label: &'static str,
+    pub blog_view_faithful: &'static str,
+    pub blog_view_text: &'static str,
+
+    // Roadmap
+    pub roadmap_title: &'static str,
+    /// Followed by a link to the TMIL month the roadmap comes from
+    pub roadmap_intro: &'static str,
+    pub roadmap_todo: &'static str,
+    pub roadmap_in_progress: &'static str,
+    pub roadmap_done: &'static str,
+    /// Followed by links to the TMIL months where an item appeared or changed state
+    pub roadmap_history: &'static str,
+    /// Heading of the summary of roadmap changes on a TMIL post
+    pub roadmap_changes_title: &'static str,
+    pub roadmap_unchanged: &'static str,
+    pub roadmap_added: &'static str,
+    pub roadmap_removed: &'static str,
+
+    // Year in review
+    /// Title of the yearly review post, with a `{year}` placeholder
+    pub review_title: &'static str,
+    /// Intro of the yearly review post, with a `{year}` placeholder
+    pub review_intro: &'static str,
+    pub review_roadmap_title: &'static str,
+
+    // Newsletter
+    /// Link from a newsletter to the post on the website
+    pub newsletter_read_online: &'static str,
+
+    // Announcements
+    /// First line of a chat announcement of a new post
+    pub announce_new_post: &'static str,
+
+    // Dates
+    pub month_names: [&'static str; 12],
+    /// Pattern with `{day}`, `{month}` (name), `{month_num}` and `{year}` placeholders
+    pub date_format: &'static str,
+
+    // Visual Identity
+    pub visual_identity_title: &'static str,
+    pub visual_identity_intro: &'static str,
+    pub visual_identity_white: IdentityText,
+    pub visual_identity_white_in_black: IdentityText,
+    pub visual_identity_black: IdentityText,
+    pub visual_identity_black_in_white: IdentityText,
+}
+
+impl Translations {
+    /// Format an ISO `YYYY-MM-DD` date with this language's `date_format`.
+    /// Values that don't parse are returned unchanged.
+    pub fn format_date(&self, iso: &str) -> String {
+        let mut parts = iso.splitn(3, '-').map(|p| p.parse::<u32>().ok());
+        let (Some(Some(year)), Some(Some(month)), Some(Some(day))) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            return iso.to_string();
+        };
+        let Some(month_name) = month
+            .checked_sub(1)
+            .and_then(|i| self.month_names.get(i as usize))
+        else {
+            return iso.to_string();
+        };
+
+        self.date_format
+            .replace("{day}", &day.to_string())
+            .replace("{month_num}", &month.to_string())
+            .replace("{month}", month_name)
+            .replace("{year}", &year.to_string())
+    }
 }
 
 pub fn get_translations() -> HashMap<&'static str, Translations> {
@@ -158,11 +291,9 @@ pub fn get_translations() -> HashMap<&'static str, Translations> {
         hero_linux_executable: "Linux executable",
         hero_macos_executable: "macOS executable",
         hero_windows_executable: "Windows executable",
-        hero_doc_buttons: vec![LinkItem::with_class(
-            INSTINTO_URL,
-            "Documentation",
-            "btn btn-primary",
-        )],
+        hero_doc_buttons: vec![
+            LinkItem::site(SiteLink::Documentation, "Documentation").with_class("btn btn-primary"),
+        ],
 
         // Index Page - Main Content
         index_content: vec![
@@ -174,10 +305,10 @@ pub fn get_translations() -> HashMap<&'static str, Translations> {
                 <br>
                 <br>
                 Everyone can do something for the documentation, code, design, legal, financial, marketing, tidying, etc.
-                If you are interested, join the <a href="https://matrix.to/#/#lince:matrix.org">Matrix</a> or
-                <a href="https://discord.gg/3Gr9rYWHpu">Discord</a> and check the end of the
-                <a href="https://raw.githubusercontent.com/lince-social/lince/dev/documentation/lince-documentation-dark.pdf">Documentation (Dark Mode)</a>
-                <a href="https://raw.githubusercontent.com/lince-social/lince/dev/documentation/lince-documentation-light.pdf">Documentation (Light Mode)</a>
+                If you are interested, join the <a href="{matrix}">Matrix</a> or
+                <a href="{discord}">Discord</a> and check the end of the
+                <a href="{documentation}">Documentation (Dark Mode)</a>
+                <a href="{documentation_light}">Documentation (Light Mode)</a>
 
                 for tasks (soon to be a DNA).
                 "#,
@@ -217,27 +348,27 @@ pub fn get_translations() -> HashMap<&'static str, Translations> {
             LinkGroup {
                 title: "Resources",
                 links: vec![
-                    LinkItem::new("https://github.com/lince-social/lince", "Lince Source"),
-                    LinkItem::new(INSTINTO_URL, "Documentation"),
-                    LinkItem::new(GITHUB_LATEST_RELEASE_URL, "Downloads"),
-                    LinkItem::new("visual-identity.html", "Visual Identity"),
-                    LinkItem::new("https://github.com/lince-social/lince-social.github.io", "Website Source"),
+                    LinkItem::site(SiteLink::Source, "Lince Source"),
+                    LinkItem::site(SiteLink::Documentation, "Documentation"),
+                    LinkItem::site(SiteLink::Releases, "Downloads"),
+                    LinkItem::new(Route::VisualIdentity, "Visual Identity"),
+                    LinkItem::site(SiteLink::WebsiteSource, "Website Source"),
                 ],
             },
             LinkGroup {
                 title: "Community",
                 links: vec![
-                    LinkItem::new("https://matrix.to/#/#lince:matrix.org", "Matrix"),
-                    LinkItem::new(YOUTUBE_URL, "Youtube"),
-                    LinkItem::new("https://discord.gg/3Gr9rYWHpu", "Discord"),
-                    LinkItem::new("https://www.instagram.com/lincesocial", "Instagram"),
-                    LinkItem::new("https://github.com/lince-social/lince/discussions", "Discussions"),
+                    LinkItem::site(SiteLink::Matrix, "Matrix"),
+                    LinkItem::site(SiteLink::YouTube, "Youtube"),
+                    LinkItem::site(SiteLink::Discord, "Discord"),
+                    LinkItem::site(SiteLink::Instagram, "Instagram"),
+                    LinkItem::site(SiteLink::Discussions, "Discussions"),
                 ],
             },
             LinkGroup {
                 title: "Legal",
                 links: vec![
-                    LinkItem::new("https://github.com/lince-social/lince/blob/main/LICENSE", "License"),
+                    LinkItem::site(SiteLink::License, "License"),
                 ],
             },
         ],
@@ -247,6 +378,78 @@ pub fn get_translations() -> HashMap<&'static str, Translations> {
 
         blog_back_to_posts: "← Back to Blog Posts",
         blog_watch_video: "Watch on YouTube",
+        blog_slides: "Slides",
+        slides_fullscreen: "Fullscreen",
+        blog_pdf: "PDF",
+        blog_no_posts: "No blog posts yet.",
+        blog_older: "← Older",
+        blog_newer: "Newer →",
+        blog_tmil_title: "This Month in Lince",
+        blog_series_previous: "← Previous in series",
+        blog_series_next: "Next in series →",
+        blog_series_label: "All posts in this series, oldest first.",
+        blog_by: "by",
+        blog_author_contact: "Contact:",
+        blog_author_posts: "Posts",
+        blog_video_play: "Play video",
+        blog_view_label: "Post view",
+        blog_view_faithful: "Faithful",
+        blog_view_text: "Text",
+        blog_video_consent: "Clicking play loads the video from youtube-nocookie.com. Nothing is requested from YouTube before that. More on our channel:",
+
+        // Roadmap
+        roadmap_title: "Roadmap",
+        roadmap_intro: "The roadmap as of the latest This Month in Lince:",
+        roadmap_todo: "To do",
+        roadmap_in_progress: "In progress",
+        roadmap_done: "Done",
+        roadmap_history: "Changed in",
+        roadmap_changes_title: "Roadmap changes this month",
+        roadmap_unchanged: "The roadmap did not change this month.",
+        roadmap_added: "Added:",
+        roadmap_removed: "Removed:",
+
+        // Year in review
+        review_title: "{year} in Review",
+        review_intro: "Everything This Month in Lince covered in {year}, month by month.",
+        review_roadmap_title: "How the roadmap evolved",
+
+        // Newsletter
+        newsletter_read_online: "Read it on the website",
+
+        // Announcements
+        announce_new_post: "New on the Lince blog:",
+
+        // Dates
+        month_names: [
+            "January", "February", "March", "April", "May", "June",
+            "July", "August", "September", "October", "November", "December",
+        ],
+        date_format: "{month} {day}, {year}",
+
+        // Visual Identity
+        visual_identity_title: "Visual Identity",
+        visual_identity_intro: "Official logo files for download in SVG, PNG, JPG, and ICO formats.",
+        visual_identity_white: IdentityText {
+            title: "White Logo",
+            description: Some("Use on dark backgrounds."),
+            alt: "Lince white logo",
+        },
+        visual_identity_white_in_black: IdentityText {
+            title: "White on Black",
+            description: Some("Logo with fixed dark background."),
+            alt: "Lince white logo on black background",
+        },
+        visual_identity_black: IdentityText {
+            title: "Black Logo",
+            description: Some("Use on light backgrounds."),
+            alt: "Lince black logo",
+        },
+        visual_identity_black_in_white: IdentityText {
+            title: "Black on White",
+            description: None,
+            alt: "Lince black logo on white background",
+        },
     });
 
     // ============================================================
@@ -276,7 +479,7 @@ pub fn get_translations() -> HashMap<&'static str, Translations> {
         hero_macos_executable: "executável para macOS",
         hero_windows_executable: "executável para Windows",
         hero_doc_buttons: vec![
-            LinkItem::with_class(INSTINTO_URL, "Instinto: Documentação Técnica", "btn btn-primary"),
+            LinkItem::site(SiteLink::Documentation, "Instinto: Documentação Técnica").with_class("btn btn-primary"),
         ],
 
         // Index Page - Main Content
@@ -290,10 +493,10 @@ pub fn get_translations() -> HashMap<&'static str, Translations> {
                 <br>
                 Todos podem contribuir com documentação, código, design, jurídico, finanças, marketing, organização, etc.
                 Se tiver interesse, entre no
-                <a href="https://matrix.to/#/#lince:matrix.org">Matrix</a> ou
-                <a href="https://discord.gg/3Gr9rYWHpu">Discord</a> e confira o fim da
-                <a href="https://raw.githubusercontent.com/lince-social/lince/dev/documentation/lince-documentation-dark.pdf">Documentação (Modo Escuro)</a>
-                <a href="https://raw.githubusercontent.com/lince-social/lince/dev/documentation/lince-documentation-light.pdf">Documentação (Modo Claro)</a>
+                <a href="{matrix}">Matrix</a> ou
+                <a href="{discord}">Discord</a> e confira o fim da
+                <a href="{documentation}">Documentação (Modo Escuro)</a>
+                <a href="{documentation_light}">Documentação (Modo Claro)</a>
                 para tarefas (em breve uma espécie de DNA).
                 "#,
             ),
@@ -331,27 +534,27 @@ pub fn get_translations() -> HashMap<&'static str, Translations> {
             LinkGroup {
                 title: "Recursos",
                 links: vec![
-                    LinkItem::new("https://github.com/lince-social/lince", "Código Fonte"),
-                    LinkItem::new(INSTINTO_URL, "Documentação"),
-                    LinkItem::new(GITHUB_LATEST_RELEASE_URL, "Downloads"),
-                    LinkItem::new("visual-identity.pt-br.html", "Identidade Visual"),
-                    LinkItem::new("https://github.com/lince-social/lince-social.github.io", "Código do Site"),
+                    LinkItem::site(SiteLink::Source, "Código Fonte"),
+                    LinkItem::site(SiteLink::Documentation, "Documentação"),
+                    LinkItem::site(SiteLink::Releases, "Downloads"),
+                    LinkItem::new(Route::VisualIdentity, "Identidade Visual"),
+                    LinkItem::site(SiteLink::WebsiteSource, "Código do Site"),
                 ],
             },
             LinkGroup {
                 title: "Comunidade",
                 links: vec![
-                    LinkItem::new("https://matrix.to/#/#lince:matrix.org", "Matrix"),
-                    LinkItem::new(YOUTUBE_URL, "YouTube"),
-                    LinkItem::new("https://discord.gg/3Gr9rYWHpu", "Discord"),
-                    LinkItem::new("https://www.instagram.com/lincesocial", "Instagram"),
-                    LinkItem::new("https://github.com/lince-social/lince/discussions", "Discussões"),
+                    LinkItem::site(SiteLink::Matrix, "Matrix"),
+                    LinkItem::site(SiteLink::YouTube, "YouTube"),
+                    LinkItem::site(SiteLink::Discord, "Discord"),
+                    LinkItem::site(SiteLink::Instagram, "Instagram"),
+                    LinkItem::site(SiteLink::Discussions, "Discussões"),
                 ],
             },
             LinkGroup {
                 title: "Legal",
                 links: vec![
-                    LinkItem::new("https://github.com/lince-social/lince/blob/main/LICENSE", "Licença"),
+                    LinkItem::site(SiteLink::License, "Licença"),
                 ],
             },
         ],
@@ -362,6 +565,78 @@ pub fn get_translations() -> HashMap<&'static str, Translations> {
 
         blog_back_to_posts: "← Voltar para Postagens do Blog",
         blog_watch_video: "Ver no YouTube",
+        blog_slides: "Slides",
+        slides_fullscreen: "Tela cheia",
+        blog_pdf: "PDF",
+        blog_no_posts: "Ainda não há postagens no blog.",
+        blog_older: "← Anterior",
+        blog_newer: "Próxima →",
+        blog_tmil_title: "Este Mês na Lince",
+        blog_series_previous: "← Anterior na série",
+        blog_series_next: "Próxima na série →",
+        blog_series_label: "Todas as postagens desta série, da mais antiga para a mais recente.",
+        blog_by: "por",
+        blog_author_contact: "Contato:",
+        blog_author_posts: "Postagens",
+        blog_video_play: "Reproduzir vídeo",
+        blog_view_label: "Visualização do post",
+        blog_view_faithful: "Fiel",
+        blog_view_text: "Texto",
+        blog_video_consent: "Ao clicar para reproduzir, o vídeo é carregado de youtube-nocookie.com. Nada é solicitado ao YouTube antes disso. Mais no nosso canal:",
+
+        // Roadmap
+        roadmap_title: "Roteiro",
+        roadmap_intro: "O roteiro segundo o último Este Mês na Lince:",
+        roadmap_todo: "A fazer",
+        roadmap_in_progress: "Em andamento",
+        roadmap_done: "Concluído",
+        roadmap_history: "Alterado em",
+        roadmap_changes_title: "Mudanças no roteiro neste mês",
+        roadmap_unchanged: "O roteiro não mudou neste mês.",
+        roadmap_added: "Adicionado:",
+        roadmap_removed: "Removido:",
+
+        // Year in review
+        review_title: "Retrospectiva de {year}",
+        review_intro: "Tudo o que o Este Mês na Lince contou em {year}, mês a mês.",
+        review_roadmap_title: "Como o roteiro evoluiu",
+
+        // Newsletter
+        newsletter_read_online: "Leia no site",
+
+        // Announcements
+        announce_new_post: "Novo no blog da Lince:",
+
+        // Dates
+        month_names: [
+            "janeiro", "fevereiro", "março", "abril", "maio", "junho",
+            "julho", "agosto", "setembro", "outubro", "novembro", "dezembro",
+        ],
+        date_format: "{day} de {month} de {year}",
+
+        // Visual Identity
+        visual_identity_title: "Identidade Visual",
+        visual_identity_intro: "Arquivos oficiais do logo para baixar nos formatos SVG, PNG, JPG e ICO.",
+        visual_identity_white: IdentityText {
+            title: "Logo Branco",
+            description: Some("Use em fundos escuros."),
+            alt: "Logo branco da Lince",
+        },
+        visual_identity_white_in_black: IdentityText {
+            title: "Branco no Preto",
+            description: Some("Logo com fundo escuro fixo."),
+            alt: "Logo branco da Lince em fundo preto",
+        },
+        visual_identity_black: IdentityText {
+            title: "Logo Preto",
+            description: Some("Use em fundos claros."),
+            alt: "Logo preto da Lince",
+        },
+        visual_identity_black_in_white: IdentityText {
+            title: "Preto no Branco",
+            description: None,
+            alt: "Logo preto da Lince em fundo branco",
+        },
     });
 
     // ============================================================
@@ -391,7 +666,7 @@ pub fn get_translations() -> HashMap<&'static str, Translations> {
         hero_macos_executable: "macOS 可执行文件",
         hero_windows_executable: "Windows 可执行文件",
         hero_doc_buttons: vec![
-            LinkItem::with_class(INSTINTO_URL, "Instinto：技术文档", "btn btn-primary"),
+            LinkItem::site(SiteLink::Documentation, "Instinto：技术文档").with_class("btn btn-primary"),
         ],
 
         // Index Page - Main Content
@@ -405,10 +680,10 @@ pub fn get_translations() -> HashMap<&'static str, Translations> {
                 <br>
                 每个人都可以为文档、代码、设计、法律、财务、市场、整理等方面做出贡献。
                 如果您有兴趣，请加入
-                <a href="https://matrix.to/#/#lince:matrix.org">Matrix</a> 或
-                <a href="https://discord.gg/3Gr9rYWHpu">Discord</a>，并查看
-                <a href="https://raw.githubusercontent.com/lince-social/lince/dev/documentation/lince-documentation-dark.pdf">文档（深色模式）</a>
-                <a href="https://raw.githubusercontent.com/lince-social/lince/dev/documentation/lince-documentation-light.pdf">文档（浅色模式）</a>
+                <a href="{matrix}">Matrix</a> 或
+                <a href="{discord}">Discord</a>，并查看
+                <a href="{documentation}">文档（深色模式）</a>
+                <a href="{documentation_light}">文档（浅色模式）</a>
                 的末尾以了解任务（很快会成为一种 DNA）。
                 "#,
             ),
@@ -446,27 +721,27 @@ pub fn get_translations() -> HashMap<&'static str, Translations> {
             LinkGroup {
                 title: "资源",
                 links: vec![
-                    LinkItem::new("https://github.com/lince-social/lince", "源代码"),
-                    LinkItem::new(INSTINTO_URL, "文档"),
-                    LinkItem::new(GITHUB_LATEST_RELEASE_URL, "下载"),
-                    LinkItem::new("visual-identity.zh.html", "视觉识别"),
-                    LinkItem::new("https://github.com/lince-social/lince-social.github.io", "网站源码"),
+                    LinkItem::site(SiteLink::Source, "源代码"),
+                    LinkItem::site(SiteLink::Documentation, "文档"),
+                    LinkItem::site(SiteLink::Releases, "下载"),
+                    LinkItem::new(Route::VisualIdentity, "视觉识别"),
+                    LinkItem::site(SiteLink::WebsiteSource, "网站源码"),
                 ],
             },
             LinkGroup {
                 title: "社区",
                 links: vec![
-                    LinkItem::new("https://matrix.to/#/#lince:matrix.org", "Matrix"),
-                    LinkItem::new(YOUTUBE_URL, "YouTube"),
-                    LinkItem::new("https://discord.gg/3Gr9rYWHpu", "Discord"),
-                    LinkItem::new("https://www.instagram.com/lincesocial", "Instagram"),
-                    LinkItem::new("https://github.com/lince-social/lince/discussions", "讨论"),
+                    LinkItem::site(SiteLink::Matrix, "Matrix"),
+                    LinkItem::site(SiteLink::YouTube, "YouTube"),
+                    LinkItem::site(SiteLink::Discord, "Discord"),
+                    LinkItem::site(SiteLink::Instagram, "Instagram"),
+                    LinkItem::site(SiteLink::Discussions, "讨论"),
                 ],
             },
             LinkGroup {
                 title: "法律",
                 links: vec![
-                    LinkItem::new("https://github.com/lince-social/lince/blob/main/LICENSE", "许可证"),
+                    LinkItem::site(SiteLink::License, "许可证"),
                 ],
             },
         ],
@@ -477,6 +752,78 @@ pub fn get_translations() -> HashMap<&'static str, Translations> {
 
         blog_back_to_posts: "← 返回博客文章",
         blog_watch_video: "在 YouTube 观看",
+        blog_slides: "幻灯片",
+        slides_fullscreen: "全屏",
+        blog_pdf: "PDF",
+        blog_no_posts: "暂无博客文章。",
+        blog_older: "← 较早",
+        blog_newer: "较新 →",
+        blog_tmil_title: "本月在 Lince",
+        blog_series_previous: "← 系列上一篇",
+        blog_series_next: "系列下一篇 →",
+        blog_series_label: "本系列的所有文章，按时间从早到晚排列。",
+        blog_by: "作者",
+        blog_author_contact: "联系方式：",
+        blog_author_posts: "文章",
+        blog_video_play: "播放视频",
+        blog_view_label: "文章视图",
+        blog_view_faithful: "原版",
+        blog_view_text: "文本",
+        blog_video_consent: "点击播放后才会从 youtube-nocookie.com 加载视频，在此之前不会向 YouTube 发送任何请求。更多内容请访问我们的频道：",
+
+        // Roadmap
+        roadmap_title: "路线图",
+        roadmap_intro: "路线图，来自最新一期本月在 Lince：",
+        roadmap_todo: "待办",
+        roadmap_in_progress: "进行中",
+        roadmap_done: "已完成",
+        roadmap_history: "变更于",
+        roadmap_changes_title: "本月路线图变化",
+        roadmap_unchanged: "本月路线图没有变化。",
+        roadmap_added: "新增：",
+        roadmap_removed: "移除：",
+
+        // Year in review
+        review_title: "{year} 年度回顾",
+        review_intro: "{year} 年本月在 Lince 的全部内容，按月整理。",
+        review_roadmap_title: "路线图的演变",
+
+        // Newsletter
+        newsletter_read_online: "在网站上阅读",
+
+        // Announcements
+        announce_new_post: "Lince 博客新文章：",
+
+        // Dates
+        month_names: [
+            "1月", "2月", "3月", "4月", "5月", "6月",
+            "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        date_format: "{year}年{month_num}月{day}日",
+
+        // Visual Identity
+        visual_identity_title: "视觉识别",
+        visual_identity_intro: "官方标志文件，可下载 SVG、PNG、JPG 和 ICO 格式。",
+        visual_identity_white: IdentityText {
+            title: "白色标志",
+            description: Some("用于深色背景。"),
+            alt: "Lince 白色标志",
+        },
+        visual_identity_white_in_black: IdentityText {
+            title: "黑底白标",
+            description: Some("带固定深色背景的标志。"),
+            alt: "黑色背景上的 Lince 白色标志",
+        },
+        visual_identity_black: IdentityText {
+            title: "黑色标志",
+            description: Some("用于浅色背景。"),
+            alt: "Lince 黑色标志",
+        },
+        visual_identity_black_in_white: IdentityText {
+            title: "白底黑标",
+            description: None,
+            alt: "白色背景上的 Lince 黑色标志",
+        },
     });
 
     map
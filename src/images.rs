@@ -0,0 +1,489 @@
+use crate::{
+    config::SiteConfig,
+    html::OutputPage,
+    sidecar::{attr, set_attr, start_tag_end},
+};
+use base64::{Engine, engine::general_purpose::STANDARD};
+use image::{
+    DynamicImage, ImageFormat,
+    codecs::{avif::AvifEncoder, jpeg::JpegEncoder, png::PngEncoder, webp::WebPEncoder},
+    imageops::FilterType,
+};
+use maud::{Markup, html};
+use std::{
+    collections::{HashSet, hash_map::DefaultHasher},
+    fs,
+    hash::{Hash, Hasher},
+    io::Cursor,
+    path::Path,
+};
+
+const OUTPUT_ROOT: &str = "output";
+const IMAGE_OUTPUT_DIR: &str = "output/assets/img";
+pub const IMAGE_PUBLIC_DIR: &str = "/assets/img";
+/// Widths generated for every raster image (never upscaled past the original).
+const IMAGE_WIDTHS: [u32; 3] = [480, 960, 1600];
+const DEFAULT_SIZES: &str = "(max-width: 768px) 100vw, 50vw";
+
+#[derive(Clone, Copy, PartialEq)]
+enum VariantFormat {
+    Avif,
+    WebP,
+    Jpeg,
+    Png,
+}
+
+impl VariantFormat {
+    /// The format a source is re-encoded to for browsers without AVIF/WebP.
+    fn fallback_for(format: ImageFormat) -> Option<Self> {
+        match format {
+            ImageFormat::Jpeg => Some(VariantFormat::Jpeg),
+            ImageFormat::Png => Some(VariantFormat::Png),
+            _ => None,
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            VariantFormat::Avif => "avif",
+            VariantFormat::WebP => "webp",
+            VariantFormat::Jpeg => "jpg",
+            VariantFormat::Png => "png",
+        }
+    }
+
+    fn mime(self) -> &'static str {
+        match self {
+            VariantFormat::Avif => "image/avif",
+            VariantFormat::WebP => "image/webp",
+            VariantFormat::Jpeg => "image/jpeg",
+            VariantFormat::Png => "image/png",
+        }
+    }
+}
+
+/// All encoded variants of one source image.
+pub struct ResponsiveImage {
+    pub width: u32,
+    pub height: u32,
+    /// Largest variant in the source's own format, used for `<img src>`.
+    pub fallback: String,
+    /// `(mime, srcset)` pairs, preferred formats first; the last one is the fallback format.
+    pub sources: Vec<(&'static str, String)>,
+}
+
+fn source_fingerprint(bytes: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn encode_variant(resized: &DynamicImage, format: VariantFormat) -> Result<Vec<u8>, String> {
+    let mut bytes = Vec::new();
+    let writer = Cursor::new(&mut bytes);
+    let result = match format {
+        VariantFormat::Avif => DynamicImage::ImageRgba8(resized.to_rgba8())
+            .write_with_encoder(AvifEncoder::new_with_speed_quality(writer, 8, 70)),
+        // The `image` crate only encodes lossless WebP, which often loses to
+        // the fallback; `process_bytes` drops it then.
+        VariantFormat::WebP => DynamicImage::ImageRgba8(resized.to_rgba8())
+            .write_with_encoder(WebPEncoder::new_lossless(writer)),
+        VariantFormat::Jpeg => DynamicImage::ImageRgb8(resized.to_rgb8())
+            .write_with_encoder(JpegEncoder::new_with_quality(writer, 82)),
+        VariantFormat::Png => resized.write_with_encoder(PngEncoder::new(writer)),
+    };
+    result.map_err(|e| format!("Failed to encode {}: {e}", format.extension()))?;
+    Ok(bytes)
+}
+
+/// Resize and re-encode a raster image into fingerprinted files under `out_dir`.
+/// File names embed the source content hash, so unchanged images are never re-encoded.
+fn process_image(
+    source: &Path,
+    out_dir: &Path,
+    public_dir: &str,
+) -> Result<ResponsiveImage, String> {
+    let bytes = fs::read(source).map_err(|e| format!("{}: {e}", source.display()))?;
+    let stem = source
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("image");
+    process_bytes(&bytes, stem, out_dir, public_dir)
+        .map_err(|e| format!("{}: {e}", source.display()))
+}
+
+/// `process_image` for an image already in memory, named after `stem`.
+fn process_bytes(
+    bytes: &[u8],
+    stem: &str,
+    out_dir: &Path,
+    public_dir: &str,
+) -> Result<ResponsiveImage, String> {
+    let fallback_format = image::guess_format(bytes)
+        .ok()
+        .and_then(VariantFormat::fallback_for)
+        .ok_or("Unsupported image format")?;
+    let fingerprint = source_fingerprint(bytes);
+    let (orig_width, orig_height) = image::ImageReader::new(Cursor::new(bytes))
+        .with_guessed_format()
+        .map_err(|e| e.to_string())?
+        .into_dimensions()
+        .map_err(|e| e.to_string())?;
+
+    let mut widths: Vec<u32> = IMAGE_WIDTHS
+        .into_iter()
+        .filter(|w| *w < orig_width)
+        .collect();
+    widths.push(orig_width.min(*IMAGE_WIDTHS.last().unwrap_or(&orig_width)));
+    widths.dedup();
+    let height_at =
+        |width: u32| ((orig_height as u64 * width as u64) / orig_width.max(1) as u64).max(1) as u32;
+    let name = |width: u32, format: VariantFormat| {
+        format!("{stem}-{width}w-{fingerprint:016x}.{}", format.extension())
+    };
+
+    fs::create_dir_all(out_dir).map_err(|e| e.to_string())?;
+    let mut decoded: Option<DynamicImage> = None;
+    for &width in &widths {
+        // The fallback is written last, so it marks a width as done.
+        let fallback_path = out_dir.join(name(width, fallback_format));
+        if fallback_path.exists() {
+            continue;
+        }
+        let img = match &decoded {
+            Some(img) => img,
+            None => decoded.insert(image::load_from_memory(bytes).map_err(|e| e.to_string())?),
+        };
+        let resized = if width == img.width() {
+            img.clone()
+        } else {
+            img.resize_exact(width, height_at(width), FilterType::Lanczos3)
+        };
+        let fallback = encode_variant(&resized, fallback_format)?;
+        let avif = encode_variant(&resized, VariantFormat::Avif)?;
+        let webp = encode_variant(&resized, VariantFormat::WebP)?;
+        let write = |format: VariantFormat, bytes: &[u8]| {
+            fs::write(out_dir.join(name(width, format)), bytes).map_err(|e| e.to_string())
+        };
+        write(VariantFormat::Avif, &avif)?;
+        if webp.len() < fallback.len() {
+            write(VariantFormat::WebP, &webp)?;
+        }
+        write(fallback_format, &fallback)?;
+    }
+
+    // WebP is only offered when it beat the fallback at every width.
+    let webp = widths
+        .iter()
+        .all(|&width| out_dir.join(name(width, VariantFormat::WebP)).exists());
+    let formats = [VariantFormat::Avif, VariantFormat::WebP, fallback_format]
+        .into_iter()
+        .filter(|&format| format != VariantFormat::WebP || webp);
+    let mut sources = Vec::new();
+    for format in formats {
+        let srcset: Vec<String> = widths
+            .iter()
+            .map(|&width| format!("{public_dir}/{} {width}w", name(width, format)))
+            .collect();
+        sources.push((format.mime(), srcset.join(", ")));
+    }
+
+    let width = *widths.last().unwrap_or(&orig_width);
+    Ok(ResponsiveImage {
+        width,
+        height: height_at(width),
+        fallback: format!("{public_dir}/{}", name(width, fallback_format)),
+        sources,
+    })
+}
+
+/// Publish a raster image into `output/assets/img` for pages that need its
+/// URLs rather than a `<picture>` (e.g. newsletters).
+pub fn publish_image(source: &Path) -> Result<ResponsiveImage, String> {
+    process_image(source, Path::new(IMAGE_OUTPUT_DIR), IMAGE_PUBLIC_DIR)
+}
+
+/// Read `width`/`height` (or the `viewBox`) from an SVG's root element.
+fn svg_dimensions(path: &Path) -> Option<(u32, u32)> {
+    let raw = fs::read_to_string(path).ok()?;
+    let start = raw.find("<svg")?;
+    let tag = &raw[start..start + raw[start..].find('>')?];
+    let attr = |name: &str| -> Option<&str> {
+        let marker = format!(" {name}=\"");
+        let from = tag.find(&marker)? + marker.len();
+        Some(&tag[from..from + tag[from..].find('"')?])
+    };
+    let number = |v: &str| v.trim_end_matches("px").parse::<f64>().ok();
+
+    if let (Some(w), Some(h)) = (
+        attr("width").and_then(number),
+        attr("height").and_then(number),
+    ) {
+        return Some((w.round() as u32, h.round() as u32));
+    }
+    let parts: Vec<f64> = attr("viewBox")?
+        .split_whitespace()
+        .filter_map(|p| p.parse().ok())
+        .collect();
+    (parts.len() == 4).then(|| (parts[2].round() as u32, parts[3].round() as u32))
+}
+
+fn non_empty(value: &str) -> Option<&str> {
+    (!value.is_empty()).then_some(value)
+}
+
+/// Render an image that lives under `output/` (`src` is its site path).
+/// Raster images become a `<picture>` with AVIF/WebP sources and a `srcset`;
+/// SVGs stay a single `<img>`. Both get explicit `width`/`height`.
+pub fn render_image(site: &SiteConfig, src: &str, alt: &str, class: &str) -> Markup {
+    let public = site.href(src);
+    let file = Path::new(OUTPUT_ROOT).join(src.trim_start_matches('/'));
+
+    if file.extension().and_then(|e| e.to_str()) == Some("svg") {
+        let dims = svg_dimensions(&file);
+        return html! {
+            img class=[non_empty(class)] src=(public) alt=(alt)
+                width=[dims.map(|d| d.0)] height=[dims.map(|d| d.1)];
+        };
+    }
+
+    match process_image(
+        &file,
+        Path::new(IMAGE_OUTPUT_DIR),
+        &site.href(IMAGE_PUBLIC_DIR),
+    ) {
+        Ok(image) => render_picture(&image, alt, class),
+        Err(err) => {
+            eprintln!("Responsive image skipped: {err}");
+            html! { img class=[non_empty(class)] src=(public) alt=(alt); }
+        }
+    }
+}
+
+fn render_picture(image: &ResponsiveImage, alt: &str, class: &str) -> Markup {
+    let (preferred, fallback) = image
+        .sources
+        .split_at(image.sources.len().saturating_sub(1));
+    html! {
+        picture {
+            @for (mime, srcset) in preferred {
+                source type=(mime) srcset=(srcset) sizes=(DEFAULT_SIZES);
+            }
+            img class=[non_empty(class)] src=(image.fallback) alt=(alt)
+                srcset=[fallback.first().map(|f| &f.1)] sizes=(DEFAULT_SIZES)
+                width=(image.width) height=(image.height)
+                loading="lazy" decoding="async";
+        }
+    }
+}
+
+/// `tag`, an `<img>`, inside a `<picture>` of `image`'s variants.
+fn picture_tag(tag: &str, image: &ResponsiveImage) -> String {
+    let (preferred, fallback) = image
+        .sources
+        .split_at(image.sources.len().saturating_sub(1));
+    let mut img = set_attr(tag, "src", &image.fallback);
+    if let Some((_, srcset)) = fallback.first() {
+        img = set_attr(&img, "srcset", srcset);
+    }
+    for (name, value) in [
+        ("sizes", DEFAULT_SIZES),
+        ("loading", "lazy"),
+        ("decoding", "async"),
+    ] {
+        img = set_attr(&img, name, value);
+    }
+    let sources = html! {
+        @for (mime, srcset) in preferred {
+            source type=(mime) srcset=(srcset) sizes=(DEFAULT_SIZES);
+        }
+    };
+    format!("<picture>{}{img}</picture>", sources.into_string())
+}
+
+/// Replace the base64 JPEG and PNG `<img>`s that Typst HTML export writes
+/// with a `<picture>` of fingerprinted variants, named `{stem}-{n}` after
+/// their position. Each `<img>` keeps its `alt`, size and style.
+pub fn responsive_inline_images(site: &SiteConfig, html: &str, stem: &str) -> String {
+    inline_images_to_pictures(
+        html,
+        stem,
+        Path::new(IMAGE_OUTPUT_DIR),
+        &site.href(IMAGE_PUBLIC_DIR),
+    )
+}
+
+fn inline_images_to_pictures(html: &str, stem: &str, out_dir: &Path, public_dir: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut rest = html;
+    let mut count = 0;
+    while let Some(pos) = rest.find("<img") {
+        out.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+        let Some(end) = start_tag_end(rest, 0) else {
+            break;
+        };
+        let tag = &rest[..end];
+        rest = &rest[end..];
+        let bytes = attr(tag, "src")
+            .and_then(|src| src.strip_prefix("data:"))
+            .and_then(|data| data.split_once(";base64,"))
+            .filter(|(mime, _)| matches!(*mime, "image/jpeg" | "image/png"))
+            .and_then(|(_, payload)| STANDARD.decode(payload).ok());
+        let Some(bytes) = bytes else {
+            out.push_str(tag);
+            continue;
+        };
+        count += 1;
+        let name = format!("{stem}-{count}");
+        match process_bytes(&bytes, &name, out_dir, public_dir) {
+            Ok(image) => out.push_str(&picture_tag(tag, &image)),
+            Err(err) => {
+                eprintln!("Responsive image skipped: {name}: {err}");
+                out.push_str(tag);
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// `{stem}-{width}w-{fingerprint}.{ext}` → `stem`.
+fn variant_stem(name: &str) -> Option<&str> {
+    let mut parts = name.rsplitn(3, '-');
+    parts.next()?;
+    parts.next()?.strip_suffix('w')?.parse::<u32>().ok()?;
+    parts.next()
+}
+
+/// File names of the variants under `/assets/img` that `pages` link to, for
+/// `remove_stale_variants`.
+pub fn used_variants(pages: &[OutputPage]) -> HashSet<String> {
+    let marker = format!("{IMAGE_PUBLIC_DIR}/");
+    let mut used = HashSet::new();
+    for (_, html) in pages {
+        for (at, _) in html.match_indices(&marker) {
+            let rest = &html[at + marker.len()..];
+            let end = rest
+                .find(['"', '\'', ' ', ',', ')', '<'])
+                .unwrap_or(rest.len());
+            used.insert(rest[..end].to_string());
+        }
+    }
+    used
+}
+
+/// Delete the variants under `output/assets/img` that earlier fingerprints of
+/// the `used` images left behind, returning how many files were removed.
+/// Images no page uses, such as newsletter photos, are kept.
+pub fn remove_stale_variants(used: &HashSet<String>) -> usize {
+    remove_stale_variants_in(Path::new(IMAGE_OUTPUT_DIR), used)
+}
+
+fn remove_stale_variants_in(dir: &Path, used: &HashSet<String>) -> usize {
+    let stems: HashSet<&str> = used.iter().filter_map(|name| variant_stem(name)).collect();
+    let Ok(entries) = fs::read_dir(dir) else {
+        return 0;
+    };
+    let mut removed = 0;
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if !used.contains(&name)
+            && variant_stem(&name).is_some_and(|stem| stems.contains(stem))
+            && fs::remove_file(entry.path()).is_ok()
+        {
+            removed += 1;
+        }
+    }
+    removed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        inline_images_to_pictures, process_image, remove_stale_variants_in, render_picture,
+        svg_dimensions, used_variants,
+    };
+    use base64::{Engine, engine::general_purpose::STANDARD};
+    use std::{fs, path::Path};
+
+    #[test]
+    fn raster_images_get_fingerprinted_avif_webp_and_fallback_variants() {
+        let out_dir = std::env::temp_dir().join("lince-website-image-test");
+        let source = Path::new("content/blog/media/random/blog_init.png");
+        let image = process_image(source, &out_dir, "/assets/img")
+            .unwrap_or_else(|e| panic!("Failed to process {}: {e}", source.display()));
+
+        let mimes: Vec<&str> = image.sources.iter().map(|s| s.0).collect();
+        assert_eq!(mimes, ["image/avif", "image/webp", "image/png"]);
+        assert!(image.width > 0 && image.height > 0);
+        assert!(image.fallback.starts_with("/assets/img/blog_init-"));
+        for (_, srcset) in &image.sources {
+            for candidate in srcset.split(", ") {
+                let href = candidate.split(' ').next().unwrap_or_default();
+                let name = href.trim_start_matches("/assets/img/");
+                assert!(out_dir.join(name).exists(), "Missing variant {href}");
+            }
+        }
+
+        let html = render_picture(&image, "alt", "img-rounded").into_string();
+        assert!(html.starts_with("<picture>"));
+        assert!(html.contains(&format!("width=\"{}\"", image.width)));
+        assert!(html.contains("type=\"image/avif\""));
+    }
+
+    #[test]
+    fn webp_is_dropped_when_it_loses_to_the_fallback() {
+        let out_dir = std::env::temp_dir().join("lince-website-image-webp-test");
+        let source = Path::new("content/blog/media/random/tmil.jpg");
+        let image = process_image(source, &out_dir, "/assets/img")
+            .unwrap_or_else(|e| panic!("Failed to process {}: {e}", source.display()));
+        let mimes: Vec<&str> = image.sources.iter().map(|s| s.0).collect();
+        assert_eq!(mimes, ["image/avif", "image/jpeg"]);
+    }
+
+    #[test]
+    fn inline_post_photos_become_pictures_and_stale_variants_are_removed() {
+        let out_dir = std::env::temp_dir().join("lince-website-inline-image-test");
+        let _ = fs::remove_dir_all(&out_dir);
+        let png = fs::read("content/blog/media/random/blog_init.png").unwrap();
+        let html = format!(
+            r#"<p><img src="data:image/png;base64,{}" alt="Blog" width="1200" height="800" style="width: 50%"></p><img src="data:image/svg+xml;base64,PHN2Zy8+" alt="Logo">"#,
+            STANDARD.encode(&png)
+        );
+        let out = inline_images_to_pictures(&html, "post", &out_dir, "/assets/img");
+
+        assert!(out.starts_with(r#"<p><picture><source type="image/avif""#));
+        assert!(out.contains(r#"style="width: 50%""#) && out.contains(r#"alt="Blog""#));
+        assert!(out.contains(r#"src="/assets/img/post-1-"#));
+        assert!(out.contains(r#"loading="lazy""#));
+        assert!(!out.contains("data:image/png"));
+        assert!(out.ends_with(r#"<img src="data:image/svg+xml;base64,PHN2Zy8+" alt="Logo">"#));
+
+        let stale = out_dir.join("post-1-480w-0000000000000000.avif");
+        let other = out_dir.join("newsletter-480w-0000000000000000.jpg");
+        fs::write(&stale, "").unwrap();
+        fs::write(&other, "").unwrap();
+        let page = ("post.html".to_string(), out.clone());
+        let used = used_variants(&[page]);
+        assert!(used.iter().all(|name| name.starts_with("post-1-")));
+        assert_eq!(remove_stale_variants_in(&out_dir, &used), 1);
+        assert!(!stale.exists() && other.exists());
+        let name = out
+            .split("/assets/img/")
+            .nth(1)
+            .unwrap()
+            .split(' ')
+            .next()
+            .unwrap();
+        assert!(out_dir.join(name).exists());
+    }
+
+    #[test]
+    fn svg_dimensions_come_from_root_attributes() {
+        assert_eq!(
+            svg_dimensions(Path::new("output/assets/logo/white.svg")),
+            Some((1105, 1105))
+        );
+    }
+}
@@ -0,0 +1,318 @@
+//! `lint tmil`: content checks for TMIL sources, reported as `path:line: message`.
+
+use crate::{
+    compiler::civil_from_days,
+    pages::blog::{BLOG_POSTS_ROOT, collect_blog_post_files, should_skip_blog_post, tmil_month},
+    tmil::{Heading, Localized, TMIL_TEMPLATE, TmilItem, TmilSection, TmilSource, read_tmil},
+};
+use std::{
+    collections::HashSet,
+    fmt,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+pub struct Diagnostic {
+    pub path: PathBuf,
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.path.display(), self.line, self.message)
+    }
+}
+
+fn current_month() -> (u32, u32) {
+    let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() / 86_400)
+        .unwrap_or_default();
+    let (year, month, _) = civil_from_days(days as i64);
+    (year as u32, month as u32)
+}
+
+fn is_cjk(ch: char) -> bool {
+    matches!(ch, '\u{3400}'..='\u{9fff}' | '\u{f900}'..='\u{faff}')
+}
+
+/// Whether a section name follows `pt-BR | zh-CN | en`: three non-empty parts,
+/// Chinese only in the middle one.
+fn section_name_is_valid(section: &TmilSection) -> bool {
+    let Some(Localized { en, pt_br, zh_cn }) = section.names() else {
+        return false;
+    };
+    !en.is_empty()
+        && !pt_br.is_empty()
+        && zh_cn.chars().any(is_cjk)
+        && !en.chars().any(is_cjk)
+        && !pt_br.chars().any(is_cjk)
+}
+
+/// Problems with the translations of one item heading.
+fn translation_problems(heading: &Localized<Heading>) -> Vec<String> {
+    let mut problems = Vec::new();
+    for (lang, translated) in [("pt-BR", &heading.pt_br), ("zh-CN", &heading.zh_cn)] {
+        for (field, english, value) in [
+            ("title", &heading.en.title, &translated.title),
+            ("subtitle", &heading.en.subtitle, &translated.subtitle),
+        ] {
+            if english.trim().is_empty() {
+                continue;
+            }
+            if value.trim().is_empty() {
+                problems.push(format!("{lang} {field} is empty (English: \"{english}\")"));
+            } else if value.trim() == english.trim() {
+                problems.push(format!("{lang} {field} copies the English \"{english}\""));
+            }
+        }
+    }
+    problems
+}
+
+/// Text of the template that a TMIL copied from it should have replaced.
+struct Placeholders<'a> {
+    email: Option<&'a str>,
+    titles: HashSet<&'a str>,
+    bodies: HashSet<&'a str>,
+}
+
+impl<'a> Placeholders<'a> {
+    fn new(template: &'a TmilSource) -> Self {
+        let items: Vec<_> = template.sections.iter().flat_map(|s| &s.items).collect();
+        Self {
+            email: template.author_email.as_ref().map(|s| s.value.as_str()),
+            titles: items
+                .iter()
+                .filter_map(|item| item.heading.as_ref())
+                .map(|heading| heading.en.title.as_str())
+                .filter(|title| !title.is_empty())
+                .collect(),
+            bodies: items
+                .iter()
+                .filter(|item| item.heading.is_some())
+                .map(|item| item.body.as_str())
+                .filter(|body| !body.is_empty())
+                .collect(),
+        }
+    }
+
+    fn title(&self, heading: &Localized<Heading>) -> bool {
+        self.titles.contains(heading.en.title.as_str())
+    }
+
+    fn body(&self, item: &TmilItem) -> bool {
+        self.bodies.contains(item.body.as_str())
+    }
+}
+
+/// Whether `source` still holds the template's placeholder author email or
+/// item text, which `lint tmil` reports: a draft not filled in yet.
+pub fn is_template_draft(source: &TmilSource, template: &TmilSource) -> bool {
+    let placeholders = Placeholders::new(template);
+    source
+        .author_email
+        .as_ref()
+        .is_some_and(|email| placeholders.email == Some(email.value.as_str()))
+        || source.sections.iter().flat_map(|s| &s.items).any(|item| {
+            item.heading
+                .as_ref()
+                .is_some_and(|heading| placeholders.title(heading) || placeholders.body(item))
+        })
+}
+
+/// Check one TMIL against the template it was copied from. `today` is the
+/// current `(year, month)`, which decides whether a month is over.
+pub fn lint_tmil(
+    path: &Path,
+    source: &TmilSource,
+    template: &TmilSource,
+    today: (u32, u32),
+) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let mut report = |line: usize, message: String| {
+        diagnostics.push(Diagnostic {
+            path: path.to_path_buf(),
+            line,
+            message,
+        })
+    };
+
+    let placeholders = Placeholders::new(template);
+
+    if let Some(email) = &source.author_email
+        && placeholders.email == Some(email.value.as_str())
+    {
+        report(
+            email.line,
+            format!(
+                "author_email is the template placeholder \"{}\"",
+                email.value
+            ),
+        );
+    }
+    if let (Some(video), Some(month)) = (&source.video_url, source.month)
+        && video.value.trim().is_empty()
+        && month < today
+    {
+        report(
+            video.line,
+            "video_url is empty for a past month".to_string(),
+        );
+    }
+
+    for section in &source.sections {
+        if !section_name_is_valid(section) {
+            report(
+                section.line,
+                format!(
+                    "section name \"{}\" does not follow \"pt-BR | zh-CN | en\"",
+                    section.name
+                ),
+            );
+        }
+        for item in &section.items {
+            let Some(heading) = &item.heading else {
+                continue;
+            };
+            if placeholders.title(heading) {
+                report(
+                    item.line,
+                    format!(
+                        "placeholder title \"{}\" left from the template",
+                        heading.en.title
+                    ),
+                );
+            }
+            if placeholders.body(item) {
+                report(
+                    item.line,
+                    format!("placeholder body \"{}\" left from the template", item.body),
+                );
+            }
+            for problem in translation_problems(heading) {
+                report(item.line, problem);
+            }
+        }
+    }
+    diagnostics
+}
+
+fn lint_all_tmil() -> Result<(), String> {
+    let template = read_tmil(Path::new(TMIL_TEMPLATE))
+        .map_err(|e| format!("Failed to read {TMIL_TEMPLATE}: {e}"))?;
+    let mut files = Vec::new();
+    collect_blog_post_files(Path::new(BLOG_POSTS_ROOT), &mut files);
+    files.retain(|path| {
+        path.file_stem()
+            .and_then(|s| s.to_str())
+            .is_some_and(|stem| !should_skip_blog_post(stem) && tmil_month(stem).is_some())
+    });
+    files.sort();
+
+    let today = current_month();
+    let mut count = 0;
+    for path in &files {
+        let source =
+            read_tmil(path).map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+        for diagnostic in lint_tmil(path, &source, &template, today) {
+            println!("{diagnostic}");
+            count += 1;
+        }
+    }
+    match count {
+        0 => {
+            println!("{} TMIL files checked, no problems", files.len());
+            Ok(())
+        }
+        _ => Err(format!("{count} problems in {} TMIL files", files.len())),
+    }
+}
+
+/// `lint tmil`.
+pub fn run(args: &[&str]) -> Result<(), String> {
+    match args {
+        ["tmil"] => lint_all_tmil(),
+        _ => Err("Usage: website lint tmil".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lint_tmil;
+    use crate::tmil::{TMIL_TEMPLATE, parse_tmil, read_tmil};
+    use std::path::Path;
+
+    fn messages(path: &str, today: (u32, u32)) -> Vec<(usize, String)> {
+        let template = read_tmil(Path::new(TMIL_TEMPLATE)).unwrap();
+        let source = read_tmil(Path::new(path)).unwrap();
+        lint_tmil(Path::new(path), &source, &template, today)
+            .into_iter()
+            .map(|d| (d.line, d.message))
+            .collect()
+    }
+
+    #[test]
+    fn template_leftovers_are_reported_with_lines() {
+        let found = messages("content/blog/posts/2026/2026_04_tmil.typ", (2026, 10));
+        for expected in [
+            (15, "author_email is the template placeholder \"a@b.c\""),
+            (16, "video_url is empty for a past month"),
+            (19, "placeholder title \"Title\" left from the template"),
+            (
+                19,
+                "placeholder body \"Normal Typst body.\" left from the template",
+            ),
+            (
+                32,
+                "placeholder title \"Another item\" left from the template",
+            ),
+        ] {
+            assert!(
+                found.contains(&(expected.0, expected.1.to_string())),
+                "Missing {expected:?} in {found:#?}"
+            );
+        }
+
+        // The current month may still get its video.
+        let found = messages("content/blog/posts/2026/2026_04_tmil.typ", (2026, 4));
+        assert!(!found.iter().any(|(_, m)| m.contains("video_url")));
+    }
+
+    #[test]
+    fn missing_translations_and_malformed_sections_are_reported() {
+        let template = read_tmil(Path::new(TMIL_TEMPLATE)).unwrap();
+        let source = parse_tmil(
+            r#"#let growth_items = (
+  tmil_item(
+    tmil_tr(
+      ("Kanban", "Drag and drop"),
+      ("Kanban", ""),
+      ("看板", "拖放"),
+    ),
+  )[
+    Done.
+  ],
+)
+
+#let sections = (
+  tmil_section("Growth | Crescimento | 成长工作", items: growth_items),
+)
+"#,
+            Some((2026, 5)),
+        );
+        let found: Vec<String> = lint_tmil(Path::new("x.typ"), &source, &template, (2026, 5))
+            .into_iter()
+            .map(|d| d.to_string())
+            .collect();
+        assert_eq!(
+            found,
+            [
+                "x.typ:14: section name \"Growth | Crescimento | 成长工作\" does not follow \"pt-BR | zh-CN | en\"",
+                "x.typ:2: pt-BR title copies the English \"Kanban\"",
+                "x.typ:2: pt-BR subtitle is empty (English: \"Drag and drop\")",
+            ]
+        );
+    }
+}
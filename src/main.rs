@@ -1,53 +1,301 @@
-use crate::config::INCLUDE_BLOG;
+use crate::config::SiteConfig;
 use crate::{
-    html::page,
-    i18n::get_translations,
+    html::{CardRenderer, OutputPage, check_links, page, route_pages, write_pages},
+    i18n::{Translations, get_translations},
     pages::{
-        blog::{generate_blog_posts, page_blog},
+        authors::{AuthorProfile, author_pages, load_author_profiles},
+        blog::{
+            BlogSidecars, blog_post_pages, page_blog, prepare_blog_sidecars, series_pages,
+            year_review_pages,
+        },
+        feed::feed_pages,
         index::page_index,
+        roadmap::page_roadmap,
+        slides::generate_slide_decks,
+        video,
         visual_identity::page_visual_identity,
     },
+    routes::Route,
 };
-use std::fs;
+use share_card::{ShareCard, share_card};
+use std::{collections::HashMap, fs};
 
+mod announcements;
+mod cache;
+mod compiler;
 mod config;
 mod html;
 mod i18n;
+mod images;
+mod lint;
 mod macros;
+mod newsletter;
 mod pages;
+mod redirects;
+mod roadmap;
+mod routes;
+mod share_card;
+mod sidecar;
+mod tmil;
 
 fn main() {
-    let translations = get_translations();
-    if INCLUDE_BLOG {
-        fs::create_dir_all("output/blog").expect("Failed to create output directory");
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let result = match args.as_slice() {
+        [] => SiteConfig::load().and_then(|site| build_site(&site)),
+        ["cache", rest @ ..] => cache::run(rest),
+        ["lint", rest @ ..] => lint::run(rest),
+        ["export", rest @ ..] => SiteConfig::load().and_then(|site| newsletter::run(rest, &site)),
+        _ => Err(
+            "Usage: website [cache <stats|clear> | lint tmil | export newsletter <YYYY-MM>]"
+                .to_string(),
+        ),
+    };
+    if let Err(err) = result {
+        eprintln!("{err}");
+        std::process::exit(2);
     }
+}
+
+fn build_site(site: &SiteConfig) -> Result<(), String> {
+    let mut translations = get_translations();
+    translations.retain(|lang_code, _| site.has_language(lang_code));
+    let profiles = load_author_profiles();
+    let sidecars = if site.sections.blog {
+        fs::create_dir_all("output/blog").expect("Failed to create output directory");
+        let sidecars = prepare_blog_sidecars(site);
+        let decks = generate_slide_decks(site, &translations, &sidecars);
+        Some(sidecars.with_decks(decks))
+    } else {
+        None
+    };
     fs::create_dir_all("output").expect("Failed to create output directory");
     fs::write("output/install.sh", include_str!("../content/install.sh"))
         .expect("Failed to write install.sh");
 
-    for (lang_code, t) in &translations {
-        let suffix = if lang_code == &"en" {
-            "".to_string()
-        } else {
-            format!(".{}", lang_code)
+    let mut pages = site_pages(
+        site,
+        &translations,
+        &profiles,
+        sidecars.as_ref(),
+        &share_card,
+    );
+    pages.extend(redirects::redirect_pages(site, &pages)?);
+    check_links(site, &pages)?;
+    let variants = images::used_variants(&pages);
+    write_pages(pages)?;
+    if site.sections.blog {
+        video::publish_thumbnails()?;
+    }
+    match images::remove_stale_variants(&variants) {
+        0 => {}
+        files => println!("Removed {files} stale image variants"),
+    }
+
+    if site.sections.blog
+        && let Err(err) = announcements::write_announcements(site, &translations)
+    {
+        eprintln!("Failed to write announcements: {err}");
+    }
+    Ok(())
+}
+
+/// Every page of the site in every configured language. Blog pages and the
+/// slide decks are only rendered with `sidecars`.
+fn site_pages(
+    site: &SiteConfig,
+    translations: &HashMap<&str, Translations>,
+    profiles: &HashMap<String, AuthorProfile>,
+    sidecars: Option<&BlogSidecars>,
+    render_card: CardRenderer,
+) -> Vec<OutputPage> {
+    // One card per page for every language; see `ShareCard`.
+    let en = &translations["en"];
+    let share_cards: Vec<(Route, String)> = [
+        (Route::Index, en.hero_title, Some(en.hero_tagline)),
+        (Route::VisualIdentity, en.visual_identity_title, None),
+        (Route::Blog, en.blog_title, None),
+        (Route::Roadmap, en.roadmap_title, None),
+    ]
+    .into_iter()
+    .filter(|(route, _, _)| match route {
+        Route::Blog => site.sections.blog,
+        Route::Roadmap => site.sections.roadmap,
+        _ => true,
+    })
+    .filter_map(|(route, title, kicker)| {
+        let card = ShareCard {
+            title,
+            kicker,
+            date: None,
         };
+        Some((route, render_card(&route.key(), &card)?))
+    })
+    .collect();
 
-        let mut pages: Vec<(&str, String)> = Vec::new();
-        pages.push(("index", page_index(t)));
-        pages.push(("visual-identity", page_visual_identity()));
-        if INCLUDE_BLOG {
-            pages.push(("blog", page_blog(t)));
+    let mut output = Vec::new();
+    for t in translations.values() {
+        let mut pages: Vec<(Route, &str, String)> = Vec::new();
+        pages.push((Route::Index, t.hero_title, page_index(t, site)));
+        pages.push((
+            Route::VisualIdentity,
+            t.visual_identity_title,
+            page_visual_identity(t, site),
+        ));
+        if site.sections.blog {
+            pages.push((Route::Blog, t.blog_title, page_blog(t, site, profiles)));
+        }
+        if site.sections.roadmap {
+            pages.push((Route::Roadmap, t.roadmap_title, page_roadmap(t, site)));
         }
 
         let show_home = pages.len() > 1;
 
-        if INCLUDE_BLOG {
-            generate_blog_posts(t, &suffix, show_home);
+        if let Some(sidecars) = sidecars {
+            output.extend(blog_post_pages(
+                t,
+                site,
+                profiles,
+                sidecars,
+                show_home,
+                render_card,
+            ));
+            output.extend(year_review_pages(t, site, show_home, render_card));
+            output.extend(series_pages(t, site, profiles, show_home));
+            output.extend(author_pages(t, site, profiles, show_home));
+            output.extend(feed_pages(t, site));
         }
 
-        for (name, content) in pages {
-            let html_out = page(&content, t, name, show_home);
-            fs::write(format!("output/{}{}.html", name, suffix), html_out).unwrap();
+        for (route, title, content) in pages {
+            let share_image = share_cards
+                .iter()
+                .find(|(card_route, _)| *card_route == route)
+                .map(|(_, image)| image.as_str());
+            let html_out = page(&content, t, site, route, title, show_home, share_image);
+            output.extend(route_pages(site, route, t.lang_code, html_out));
         }
     }
+    if let Some(sidecars) = sidecars {
+        output.extend_from_slice(sidecars.decks());
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{redirects::redirect_pages, site_pages};
+    use crate::{
+        config::SiteConfig,
+        html::{check_links, route_pages, write_pages},
+        i18n::get_translations,
+        pages::{authors::load_author_profiles, blog::BlogSidecars},
+        routes::Route,
+    };
+
+    #[test]
+    fn every_internal_link_resolves_under_a_nested_base_path() {
+        let raw = std::fs::read_to_string("site.toml").unwrap();
+        let site = SiteConfig::parse(&raw.replacen(
+            "base_path = \"\"",
+            "base_path = \"/preview/pr-42\"",
+            1,
+        ))
+        .unwrap();
+        let post = Route::BlogPost("2026/2026_04_tmil");
+        let deck = Route::SlideDeck("2026/04");
+        let back = format!(r#"<a href="{}">2026-04</a>"#, post.href(&site, "zh"));
+        let sidecars =
+            BlogSidecars::placeholders().with_decks(route_pages(&site, deck, "zh", back));
+        let mut pages = site_pages(
+            &site,
+            &get_translations(),
+            &load_author_profiles(),
+            Some(&sidecars),
+            &|_, _| None,
+        );
+        pages.extend(redirect_pages(&site, &pages).unwrap());
+        let html = |route: Route, lang: &str| {
+            let path = route.path(&site, lang);
+            pages
+                .iter()
+                .find(|(page, _)| *page == path)
+                .map(|(_, html)| html.clone())
+                .unwrap_or_else(|| panic!("{path} is not built"))
+        };
+        // Decks are pages like any other, linked from their post per language.
+        assert!(html(post, "zh").contains(r#"href="/preview/pr-42/slides/2026/04.zh.html""#));
+        assert!(!html(post, "en").contains("blog-slides-link"));
+        assert!(html(deck, "zh").contains("2026_04_tmil.zh.html"));
+        // Link previews name the page and point at its absolute URL.
+        let index = html(Route::Index, "en");
+        assert!(index.contains("<title>Lince</title>"));
+        assert!(index.contains(r#"<meta property="og:type" content="website">"#));
+        let roadmap = html(Route::Roadmap, "en");
+        assert!(roadmap.contains("<title>Roadmap | Lince</title>"));
+        assert!(roadmap.contains(r#"<meta property="og:title" content="Roadmap">"#));
+        let post_html = html(post, "zh");
+        assert!(post_html.contains(r#"<meta property="og:type" content="article">"#));
+        assert!(post_html.contains(&format!(
+            r#"<meta property="og:url" content="{}">"#,
+            post.url(&site, "zh")
+        )));
+        assert!(
+            pages
+                .iter()
+                .any(|(path, _)| *path == Route::BlogPost("2026/2026_02_tmil").path(&site, "zh"))
+        );
+        check_links(&site, &pages).unwrap_or_else(|e| panic!("{e}"));
+
+        // A link to a page that is not built fails the check.
+        pages.retain(|(path, _)| *path != Route::Roadmap.path(&site, "pt-br"));
+        let err = check_links(&site, &pages).unwrap_err();
+        assert!(err.contains("`/preview/pr-42/roadmap.pt-br.html`, which is not built"));
+
+        // Nor does a page an earlier build left in `output/`.
+        let stale = Route::VisualIdentity.path(&site, "zh");
+        assert!(std::path::Path::new("output").join(&stale).is_file());
+        pages.retain(|(path, _)| *path != stale);
+        let err = check_links(&site, &pages).unwrap_err();
+        assert!(err.contains("`/preview/pr-42/visual-identity.zh.html`, which is not built"));
+    }
+
+    #[test]
+    fn directory_urls_resolve_and_old_paths_redirect() {
+        let raw = std::fs::read_to_string("site.toml").unwrap();
+        let site = SiteConfig::parse(
+            &raw.replacen("base_path = \"\"", "base_path = \"/preview/pr-42\"", 1)
+                .replacen("url_scheme = \"suffix\"", "url_scheme = \"directories\"", 1),
+        )
+        .unwrap();
+        let mut pages = site_pages(
+            &site,
+            &get_translations(),
+            &load_author_profiles(),
+            Some(&BlogSidecars::placeholders()),
+            &|_, _| None,
+        );
+        check_links(&site, &pages).unwrap_or_else(|e| panic!("{e}"));
+
+        let page = |path: &str| {
+            pages
+                .iter()
+                .find(|(page_path, _)| page_path == path)
+                .map(|(_, html)| html.as_str())
+                .unwrap_or_else(|| panic!("{path} is not built"))
+        };
+        assert!(page("pt-br/blog/2026/04/tmil/index.html").contains(r#"<html lang="pt-br""#));
+        let stub = page("blog/2026/2026_04_tmil.pt-br.html");
+        assert!(stub.contains(r#"content="0; url=/preview/pr-42/pt-br/blog/2026/04/tmil/""#));
+        assert!(stub.contains(
+            r#"rel="canonical" href="https://lince.social/preview/pr-42/pt-br/blog/2026/04/tmil/""#
+        ));
+
+        // A post whose directory is already taken is rejected before anything is written.
+        let clash = Route::BlogPost("2026/04_tmil");
+        pages.extend(route_pages(&site, clash, "pt-br", String::new()));
+        assert_eq!(
+            write_pages(pages).unwrap_err(),
+            "Two pages are generated at output/pt-br/blog/2026/04/tmil/index.html"
+        );
+    }
 }
@@ -0,0 +1,440 @@
+//! `export newsletter <YYYY-MM>`: a TMIL as email-safe HTML (table layout,
+//! inline styles, absolute URLs) plus a plain-text alternative, one pair per
+//! language, written to `output-newsletter/<YYYY-MM>/` for any mailing tool.
+
+use crate::{
+    config::SiteConfig,
+    i18n::{Translations, get_translations},
+    images::publish_image,
+    pages::{
+        blog::{
+            BLOG_POSTS_ROOT, collect_blog_post_files, get_blog_posts, localized_post_title,
+            should_skip_blog_post, slug_from_path, tmil_month,
+        },
+        roadmap::state_label,
+    },
+    roadmap::{ItemState, RoadmapItem, parse_roadmap},
+    routes::Route,
+    tmil::{TmilItem, TmilSource, parse_tmil},
+};
+use maud::{DOCTYPE, Markup, html};
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// Not deployed: exports are sent by hand.
+const NEWSLETTER_DIR: &str = "output-newsletter";
+/// Directory `tmil_photo_path` resolves `photo:` paths against.
+const TMIL_MEDIA_DIR: &str = "content/blog/media";
+/// Width of the message body; wider tables break in most mail clients.
+const EMAIL_WIDTH: u32 = 600;
+const EMAIL_PADDING: u32 = 24;
+
+const STYLE_PAGE: &str = "margin:0;padding:0;background-color:#f2f2f2;";
+const STYLE_CARD: &str = "background-color:#ffffff;color:#111111;font-family:Helvetica,Arial,sans-serif;font-size:16px;line-height:1.5;";
+const STYLE_CELL: &str = "padding:0 24px 16px 24px;";
+const STYLE_H1: &str = "margin:0;font-size:26px;line-height:1.25;font-weight:bold;";
+const STYLE_H2: &str = "margin:16px 0 0 0;padding-top:16px;border-top:1px solid #dddddd;font-size:20px;font-weight:bold;";
+const STYLE_H3: &str = "margin:0;font-size:17px;font-weight:bold;";
+const STYLE_SUBTITLE: &str = "margin:2px 0 0 0;color:#666666;font-size:14px;";
+const STYLE_TEXT: &str = "margin:8px 0 0 0;";
+const STYLE_LINK: &str = "color:#1a5fb4;text-decoration:underline;";
+const STYLE_IMG: &str =
+    "display:block;border:0;outline:none;margin-top:8px;max-width:100%;height:auto;";
+const STYLE_LIST: &str = "margin:4px 0 0 0;padding-left:20px;";
+const STYLE_FOOTER: &str = "padding:16px 24px 24px 24px;color:#666666;font-size:13px;";
+
+/// One TMIL, parsed once and rendered in every language.
+struct Edition {
+    slug: String,
+    /// English post title, localized per language.
+    title: String,
+    source: TmilSource,
+    roadmap: Vec<RoadmapItem>,
+    /// Published photo per `photo:` path: absolute URL, width, height.
+    photos: HashMap<String, (String, u32, u32)>,
+}
+
+/// Parse `YYYY-MM`.
+fn parse_month(value: &str) -> Option<(u32, u32)> {
+    let (year, month) = value.split_once('-')?;
+    let month: u32 = month.parse().ok()?;
+    (1..=12)
+        .contains(&month)
+        .then_some((year.parse().ok()?, month))
+}
+
+fn find_tmil(year: u32, month: u32) -> Option<PathBuf> {
+    let mut files = Vec::new();
+    collect_blog_post_files(Path::new(BLOG_POSTS_ROOT), &mut files);
+    files.into_iter().find(|path| {
+        path.file_stem()
+            .and_then(|s| s.to_str())
+            .is_some_and(|stem| {
+                !should_skip_blog_post(stem) && tmil_month(stem) == Some((year, month))
+            })
+    })
+}
+
+fn read_edition(year: u32, month: u32) -> Result<Edition, String> {
+    let path = find_tmil(year, month)
+        .ok_or_else(|| format!("No TMIL for {year}-{month:02} in {BLOG_POSTS_ROOT}"))?;
+    let content =
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+    let slug = slug_from_path(&path).unwrap_or_default();
+    let title = get_blog_posts()
+        .into_iter()
+        .find(|post| post.slug == slug)
+        .map(|post| post.title)
+        .unwrap_or_else(|| format!("{year}-{month:02}"));
+    Ok(Edition {
+        slug,
+        title,
+        source: parse_tmil(&content, Some((year, month))),
+        roadmap: parse_roadmap(&content),
+        photos: HashMap::new(),
+    })
+}
+
+/// Whether a file under `output/` is live: the deploy workflow publishes the
+/// committed `output/` as it is, so that means tracked by git.
+fn is_deployed(path: &Path) -> bool {
+    Command::new("git")
+        .args(["ls-files", "--error-unmatch", "--"])
+        .arg(path)
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+/// Publish every item photo into the site's image assets, so mail clients can
+/// load them from the deployed site. Photos that fail are left out; photos
+/// that are not deployed yet fail the export, since every reader would get a
+/// broken image.
+fn publish_photos(site: &SiteConfig, edition: &mut Edition) -> Result<(), String> {
+    let photos: Vec<String> = edition
+        .source
+        .sections
+        .iter()
+        .flat_map(|section| &section.items)
+        .filter_map(|item| item.photo.clone())
+        .collect();
+    let mut undeployed = Vec::new();
+    for photo in photos {
+        let file = Path::new(TMIL_MEDIA_DIR).join(photo.trim_start_matches("media/"));
+        match publish_image(&file) {
+            Ok(image) => {
+                let published = Path::new("output").join(image.fallback.trim_start_matches('/'));
+                if !is_deployed(&published) {
+                    undeployed.push(published.display().to_string());
+                }
+                let width = image.width.min(EMAIL_WIDTH - 2 * EMAIL_PADDING);
+                let height =
+                    (image.height as u64 * width as u64 / image.width.max(1) as u64) as u32;
+                edition
+                    .photos
+                    .insert(photo, (site.url(&image.fallback), width, height));
+            }
+            Err(err) => eprintln!("Newsletter photo skipped: {err}"),
+        }
+    }
+    if undeployed.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Newsletter photos are not deployed yet; commit and deploy them, then export again:\n  {}",
+            undeployed.join("\n  ")
+        ))
+    }
+}
+
+/// Paragraphs of an item body, with Typst line breaks (`\`) joined.
+fn paragraphs(body: &str) -> Vec<String> {
+    body.split("\n\n")
+        .map(|paragraph| {
+            paragraph
+                .lines()
+                .map(|line| line.trim().trim_end_matches('\\').trim_end())
+                .filter(|line| !line.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .filter(|paragraph| !paragraph.is_empty())
+        .collect()
+}
+
+fn text_marker(state: ItemState) -> &'static str {
+    match state {
+        ItemState::Todo => "[ ]",
+        ItemState::InProgress => "[/]",
+        ItemState::Done => "[x]",
+    }
+}
+
+fn post_url(t: &Translations, site: &SiteConfig, edition: &Edition) -> String {
+    Route::BlogPost(&edition.slug).url(site, t.lang_code)
+}
+
+fn render_html(t: &Translations, site: &SiteConfig, edition: &Edition) -> String {
+    let title = localized_post_title(t, &edition.title);
+    let video_url = edition.source.video_url.as_ref().map(|v| v.value.as_str());
+
+    html! {
+        (DOCTYPE)
+        html lang=(t.lang_code) {
+            head {
+                meta charset="UTF-8";
+                meta name="viewport" content="width=device-width, initial-scale=1.0";
+                title { (title) }
+            }
+            body style=(STYLE_PAGE) {
+                table role="presentation" width="100%" cellpadding="0" cellspacing="0" border="0" style=(STYLE_PAGE) {
+                    tr {
+                        td align="center" style="padding:24px 12px;" {
+                            table role="presentation" width=(EMAIL_WIDTH) cellpadding="0" cellspacing="0" border="0"
+                                style=(format!("width:{EMAIL_WIDTH}px;max-width:{EMAIL_WIDTH}px;{STYLE_CARD}")) {
+                                tr {
+                                    td style=(format!("padding:{EMAIL_PADDING}px {EMAIL_PADDING}px 16px {EMAIL_PADDING}px;")) {
+                                        img src=(site.url("/assets/logo/black.png")) alt="Lince" width="48" height="48"
+                                            style="display:block;border:0;";
+                                    }
+                                }
+                                tr {
+                                    td style=(STYLE_CELL) {
+                                        h1 style=(STYLE_H1) { (title) }
+                                        p style=(STYLE_TEXT) {
+                                            a href=(post_url(t, site, edition)) style=(STYLE_LINK) { (t.newsletter_read_online) }
+                                            @if let Some(video_url) = video_url.filter(|url| !url.is_empty()) {
+                                                " · "
+                                                a href=(video_url) style=(STYLE_LINK) { (t.blog_watch_video) }
+                                            }
+                                        }
+                                    }
+                                }
+                                @for section in &edition.source.sections {
+                                    @let name = section.names().map(|names| names.get(t.lang_code).clone());
+                                    tr {
+                                        td style=(STYLE_CELL) {
+                                            h2 style=(STYLE_H2) { (name.unwrap_or_else(|| section.name.clone())) }
+                                        }
+                                    }
+                                    @for item in &section.items {
+                                        @if item.heading.is_some() {
+                                            tr { td style=(STYLE_CELL) { (render_html_item(t, edition, item)) } }
+                                        } @else if !edition.roadmap.is_empty() {
+                                            tr { td style=(STYLE_CELL) { (render_html_roadmap(t, &edition.roadmap)) } }
+                                        }
+                                    }
+                                }
+                                tr {
+                                    td style=(STYLE_FOOTER) {
+                                        a href=(Route::Index.url(site, t.lang_code)) style=(STYLE_LINK) {
+                                            (site.host())
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    .into_string()
+}
+
+fn render_html_item(t: &Translations, edition: &Edition, item: &TmilItem) -> Markup {
+    let heading = item
+        .heading
+        .as_ref()
+        .map(|heading| heading.get(t.lang_code));
+    let photo = item
+        .photo
+        .as_ref()
+        .and_then(|photo| edition.photos.get(photo));
+
+    html! {
+        @if let Some(heading) = heading {
+            h3 style=(STYLE_H3) { (heading.title) }
+            @if !heading.subtitle.is_empty() {
+                p style=(STYLE_SUBTITLE) { (heading.subtitle) }
+            }
+            @if let Some((src, width, height)) = photo {
+                img src=(src) alt=(heading.title) width=(width) height=(height) style=(STYLE_IMG);
+            }
+        }
+        @for paragraph in paragraphs(&item.body) {
+            p style=(STYLE_TEXT) { (paragraph) }
+        }
+    }
+}
+
+fn render_html_roadmap(t: &Translations, items: &[RoadmapItem]) -> Markup {
+    html! {
+        ul style=(STYLE_LIST) {
+            @for item in items {
+                @let (_, symbol, label) = state_label(t, item.state);
+                li {
+                    span title=(label) { (symbol) } " " (item.text)
+                    @if !item.children.is_empty() {
+                        (render_html_roadmap(t, &item.children))
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_text(t: &Translations, site: &SiteConfig, edition: &Edition) -> String {
+    let title = localized_post_title(t, &edition.title);
+    let underline = |text: &str, ch: char| ch.to_string().repeat(text.chars().count());
+    let mut out = format!("{title}\n{}\n\n", underline(&title, '='));
+    out.push_str(&format!(
+        "{}: {}\n",
+        t.newsletter_read_online,
+        post_url(t, site, edition)
+    ));
+    if let Some(video) = &edition.source.video_url
+        && !video.value.is_empty()
+    {
+        out.push_str(&format!("{}: {}\n", t.blog_watch_video, video.value));
+    }
+
+    for section in &edition.source.sections {
+        let name = section
+            .names()
+            .map(|names| names.get(t.lang_code).clone())
+            .unwrap_or_else(|| section.name.clone());
+        out.push_str(&format!("\n\n{name}\n{}\n", underline(&name, '-')));
+        for item in &section.items {
+            match &item.heading {
+                Some(heading) => {
+                    let heading = heading.get(t.lang_code);
+                    out.push_str(&format!("\n* {}", heading.title));
+                    if !heading.subtitle.is_empty() {
+                        out.push_str(&format!(" — {}", heading.subtitle));
+                    }
+                    out.push('\n');
+                    for paragraph in paragraphs(&item.body) {
+                        out.push_str(&format!("  {paragraph}\n"));
+                    }
+                    if let Some((src, _, _)) =
+                        item.photo.as_ref().and_then(|p| edition.photos.get(p))
+                    {
+                        out.push_str(&format!("  {src}\n"));
+                    }
+                }
+                None => {
+                    out.push('\n');
+                    push_text_roadmap(&mut out, &edition.roadmap, 0);
+                }
+            }
+        }
+    }
+    out.push_str(&format!("\n\n-- \n{}\n", site.base_url));
+    out
+}
+
+fn push_text_roadmap(out: &mut String, items: &[RoadmapItem], depth: usize) {
+    for item in items {
+        out.push_str(&format!(
+            "{}{} {}\n",
+            "  ".repeat(depth),
+            text_marker(item.state),
+            item.text
+        ));
+        push_text_roadmap(out, &item.children, depth + 1);
+    }
+}
+
+fn export_newsletter(site: &SiteConfig, month: &str) -> Result<(), String> {
+    let (year, month) =
+        parse_month(month).ok_or_else(|| format!("Expected a month as YYYY-MM, got `{month}`"))?;
+    let mut edition = read_edition(year, month)?;
+    publish_photos(site, &mut edition)?;
+
+    let dir = Path::new(NEWSLETTER_DIR).join(format!("{year}-{month:02}"));
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create {}: {e}", dir.display()))?;
+    let mut translations: Vec<_> = get_translations()
+        .into_iter()
+        .filter(|(lang_code, _)| site.has_language(lang_code))
+        .collect();
+    translations.sort_by_key(|(lang_code, _)| *lang_code);
+    for (lang_code, t) in &translations {
+        for (extension, content) in [
+            ("html", render_html(t, site, &edition)),
+            ("txt", render_text(t, site, &edition)),
+        ] {
+            let path = dir.join(format!("{lang_code}.{extension}"));
+            fs::write(&path, content)
+                .map_err(|e| format!("Failed to write {}: {e}", path.display()))?;
+            println!("{}", path.display());
+        }
+    }
+    Ok(())
+}
+
+/// `export newsletter <YYYY-MM>`.
+pub fn run(args: &[&str], site: &SiteConfig) -> Result<(), String> {
+    match args {
+        ["newsletter", month] => export_newsletter(site, month),
+        _ => Err("Usage: website export newsletter <YYYY-MM>".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_deployed, parse_month, read_edition, render_html, render_text};
+    use crate::{config::SiteConfig, i18n::get_translations};
+    use std::path::Path;
+
+    #[test]
+    fn only_committed_outputs_count_as_deployed() {
+        assert!(is_deployed(Path::new("output/assets/style.css")));
+        assert!(!is_deployed(Path::new(
+            "output/assets/img/white_in_black-552.png"
+        )));
+    }
+
+    #[test]
+    fn newsletters_are_email_safe_and_localized() {
+        assert_eq!(parse_month("2026-02"), Some((2026, 2)));
+        assert_eq!(parse_month("2026-13"), None);
+
+        let mut edition = read_edition(2026, 2).unwrap();
+        edition.photos.insert(
+            "logo/white_in_black.png".to_string(),
+            (
+                "https://lince.social/assets/img/white_in_black.png".to_string(),
+                552,
+                552,
+            ),
+        );
+        let t = &get_translations()["pt-br"];
+        let site = SiteConfig::load().unwrap();
+
+        let html = render_html(t, &site, &edition);
+        assert!(html.contains(r#"<table role="presentation" width="600""#));
+        assert!(
+            !html.contains("<style") && !html.contains("class="),
+            "CSS must be inlined"
+        );
+        assert!(
+            !html.contains("src=\"/") && !html.contains("href=\"/"),
+            "URLs must be absolute"
+        );
+        assert!(html.contains(r#"href="https://lince.social/blog/2026/2026_02_tmil.pt-br.html""#));
+        assert!(html.contains("Este Mês na Lince | 2026-02"));
+        assert!(html.contains(">Crescimento</h2>"));
+        assert!(html.contains(">Novo Logo</h3>"));
+        assert!(html.contains(r#"src="https://lince.social/assets/img/white_in_black.png""#));
+        assert!(html.contains(r#"<span title="Em andamento">◐</span> v1.0.0"#));
+
+        let text = render_text(t, &site, &edition);
+        assert!(text.starts_with("Este Mês na Lince | 2026-02\n==="));
+        assert!(text.contains("\n* Novo Logo — Feito pela Nica\n  Look at it!\n"));
+        assert!(text.contains("\n[/] v1.0.0: Todo Rewrite of Frontend in GPUI\n  [/] Todo\n"));
+    }
+}
@@ -0,0 +1,215 @@
+use crate::{
+    config::SiteConfig,
+    html::{OutputPage, page, route_pages},
+    i18n::Translations,
+    pages::blog::{BlogPost, PostAuthor, get_blog_posts, render_blog_post_item, slugify},
+    routes::Route,
+};
+use maud::{PreEscaped, html};
+use serde::Deserialize;
+use std::{collections::HashMap, fs};
+
+const AUTHORS_PATH: &str = "content/blog/authors.toml";
+
+/// Profile of a post author, keyed in `authors.toml` by the `author_name` used in posts.
+#[derive(Clone, Deserialize)]
+pub struct AuthorProfile {
+    pub display_name: String,
+    /// Path to the avatar image (relative to output directory)
+    #[serde(default)]
+    pub avatar: Option<String>,
+    #[serde(default)]
+    pub links: Vec<AuthorLink>,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct AuthorLink {
+    pub label: String,
+    pub href: String,
+}
+
+#[derive(Deserialize)]
+struct AuthorsFile {
+    #[serde(default)]
+    authors: HashMap<String, AuthorProfile>,
+}
+
+pub fn load_author_profiles() -> HashMap<String, AuthorProfile> {
+    let Ok(raw) = fs::read_to_string(AUTHORS_PATH) else {
+        return HashMap::new();
+    };
+    toml::from_str::<AuthorsFile>(&raw)
+        .unwrap_or_else(|e| panic!("Invalid author profiles in {AUTHORS_PATH}: {e}"))
+        .authors
+}
+
+pub fn author_display_name<'a>(
+    profiles: &'a HashMap<String, AuthorProfile>,
+    author: &'a PostAuthor,
+) -> &'a str {
+    profiles
+        .get(&author.name)
+        .map(|p| p.display_name.as_str())
+        .unwrap_or(&author.name)
+}
+
+pub fn author_href(site: &SiteConfig, author: &PostAuthor, lang_code: &str) -> String {
+    Route::Author(&slugify(&author.name)).href(site, lang_code)
+}
+
+/// Spell out `@` and `.` and encode every character as an HTML entity, so the
+/// address stays readable in the browser but never appears verbatim in the HTML.
+pub fn obfuscate_email(email: &str) -> String {
+    email
+        .replace('@', " [at] ")
+        .replace('.', " [dot] ")
+        .chars()
+        .map(|c| format!("&#{};", c as u32))
+        .collect()
+}
+
+fn page_author(
+    t: &Translations,
+    site: &SiteConfig,
+    profiles: &HashMap<String, AuthorProfile>,
+    author: &PostAuthor,
+    posts: &[&BlogPost],
+) -> String {
+    let blog_href = Route::Blog.href(site, t.lang_code);
+    let profile = profiles.get(&author.name);
+
+    html! {
+        main.main-content {
+            nav.breadcrumbs.blog-breadcrumbs {
+                a.blog-back-link href=(blog_href) { (t.blog_back_to_posts) }
+            }
+            section.blog-header.author-header {
+                @if let Some(avatar) = profile.and_then(|p| p.avatar.as_deref()) {
+                    img.author-avatar src=(site.href(avatar)) alt=(author_display_name(profiles, author));
+                }
+                h1.section-title { (author_display_name(profiles, author)) }
+                @if let Some(email) = &author.email {
+                    p.section-description.author-email {
+                        (t.blog_author_contact) " "
+                        span { (PreEscaped(obfuscate_email(email))) }
+                    }
+                }
+                @if let Some(profile) = profile && !profile.links.is_empty() {
+                    p.section-description.author-links {
+                        @for (i, link) in profile.links.iter().enumerate() {
+                            @if i > 0 { " • " }
+                            a href=(link.href) { (link.label) }
+                        }
+                    }
+                }
+            }
+            section.blog-posts-container {
+                h2.content-block__title { (t.blog_author_posts) }
+                ul.blog-posts-list {
+                    @for post in posts {
+                        (render_blog_post_item(t, site, profiles, post))
+                    }
+                }
+            }
+        }
+    }
+    .0
+}
+
+pub fn author_pages(
+    t: &Translations,
+    site: &SiteConfig,
+    profiles: &HashMap<String, AuthorProfile>,
+    show_home: bool,
+) -> Vec<OutputPage> {
+    let posts = get_blog_posts(); // latest first
+    let mut by_author: Vec<(String, PostAuthor, Vec<&BlogPost>)> = Vec::new();
+    for post in &posts {
+        let Some(author) = &post.author else {
+            continue;
+        };
+        let slug = slugify(&author.name);
+        match by_author.iter_mut().find(|(s, _, _)| s == &slug) {
+            Some((_, _, entries)) => entries.push(post),
+            // The latest post decides which email is shown on the author page.
+            None => by_author.push((slug, author.clone(), vec![post])),
+        }
+    }
+
+    by_author
+        .into_iter()
+        .flat_map(|(slug, author, entries)| {
+            let route = Route::Author(&slug);
+            let content = page_author(t, site, profiles, &author, &entries);
+            let name = author_display_name(profiles, &author);
+            let final_html = page(&content, t, site, route, name, show_home, None);
+            route_pages(site, route, t.lang_code, final_html)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{load_author_profiles, obfuscate_email, page_author};
+    use crate::config::SiteConfig;
+    use crate::i18n::get_translations;
+    use crate::pages::blog::{BlogSidecars, blog_post_pages, get_blog_posts};
+
+    #[test]
+    fn author_profiles_file_parses() {
+        let profiles = load_author_profiles();
+        assert!(
+            profiles.values().all(|p| !p.display_name.trim().is_empty()),
+            "Author profiles must have a display name"
+        );
+    }
+
+    #[test]
+    fn author_pages_do_not_contain_plain_emails() {
+        assert!(!obfuscate_email("xaviduds@gmail.com").contains("gmail.com"));
+
+        let translations = get_translations();
+        let profiles = load_author_profiles();
+        let site = SiteConfig::load().unwrap();
+        let posts = get_blog_posts();
+        for post in &posts {
+            let Some(author) = &post.author else {
+                continue;
+            };
+            let Some(email) = &author.email else {
+                continue;
+            };
+            let html = page_author(&translations["en"], &site, &profiles, author, &[post]);
+            assert!(
+                !html.contains(email.as_str()),
+                "Author page for {} leaks the plain email address",
+                author.name
+            );
+        }
+
+        let emails: Vec<&str> = posts
+            .iter()
+            .filter_map(|post| post.author.as_ref()?.email.as_deref())
+            .collect();
+        assert!(!emails.is_empty());
+        let pages = blog_post_pages(
+            &translations["en"],
+            &site,
+            &profiles,
+            &BlogSidecars::placeholders(),
+            false,
+            &|_, _| None,
+        );
+        assert!(
+            pages
+                .iter()
+                .any(|(_, html)| html.contains("blog-post-text"))
+        );
+        for (path, html) in &pages {
+            assert!(
+                !emails.iter().any(|email| html.contains(email)),
+                "{path} leaks a plain email address"
+            );
+        }
+    }
+}
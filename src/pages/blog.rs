@@ -1,55 +1,135 @@
-use crate::{html::page, i18n::Translations};
-use maud::{PreEscaped, html};
+use crate::{
+    cache::{SIDECAR_CACHE_DIR, collect_garbage, typst_fingerprint},
+    compiler::{OutputFormat, TypstBackend, default_backend},
+    config::SiteConfig,
+    html::{CardRenderer, OutputPage, page, route_pages},
+    i18n::Translations,
+    images::responsive_inline_images,
+    pages::{
+        authors::{AuthorProfile, author_display_name, author_href, obfuscate_email},
+        review::{render_review, review_posts, review_title},
+        roadmap::render_roadmap_changes,
+        slides::deck_href,
+        video::{PostVideo, render_video_facade},
+    },
+    roadmap::{RoadmapChange, roadmap_changes, tmil_roadmaps},
+    routes::Route,
+    share_card::ShareCard,
+    sidecar::{
+        INLINE_IMAGE_LIMIT, externalize_images, largest_inline_image, layout_typst_svg,
+        link_glyphs, split_glyphs, write_glyph_sprite,
+    },
+};
+use maud::{Markup, PreEscaped, html};
 use std::{
-    collections::HashMap,
-    collections::hash_map::DefaultHasher,
+    collections::{BTreeMap, HashMap, HashSet},
     fs,
-    hash::{Hash, Hasher},
     path::{Path, PathBuf},
-    process::Command,
 };
 
-const BLOG_POSTS_ROOT: &str = "content/blog/posts";
+pub const BLOG_POSTS_ROOT: &str = "content/blog/posts";
+pub const BLOG_ASSETS_DIR: &str = "output/assets/blog";
+pub const BLOG_ASSETS_PUBLIC_DIR: &str = "/assets/blog";
+/// Default `prefix` of `tmil_post_title` in `content/blog/tmil.typ`.
+const TMIL_TITLE_PREFIX: &str = "This Month in Lince";
+/// Series implied for every `*_tmil.typ` post.
+const TMIL_SERIES: &str = "tmil";
 
 #[derive(Default)]
 struct BlogMetadata {
     title: Option<String>,
     date: Option<String>,
     video_url: Option<String>,
+    series: Option<String>,
+    author: Option<PostAuthor>,
+}
+
+/// Author as written in the post source (`author_name`/`author_email` or `name:`/`email:`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct PostAuthor {
+    pub name: String,
+    pub email: Option<String>,
+}
+
+/// Where a listed post comes from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PostKind {
+    /// A Typst source under `BLOG_POSTS_ROOT`.
+    Typst,
+    /// The year in review of a year, generated from its TMILs.
+    Review(u32),
+}
+
+/// A listed blog post, as shown on the blog index and used for navigation.
+#[derive(Clone)]
+pub struct BlogPost {
+    pub slug: String,
+    pub title: String,
+    pub date: String,
+    pub series: Option<String>,
+    pub author: Option<PostAuthor>,
+    pub kind: PostKind,
+}
+
+impl BlogPost {
+    /// Title in the language of `t`.
+    pub fn localized_title(&self, t: &Translations) -> String {
+        match self.kind {
+            PostKind::Review(year) => review_title(t, year),
+            PostKind::Typst => localized_post_title(t, &self.title),
+        }
+    }
 }
 
+/// Links to the surrounding posts of the same series.
+struct SeriesNav {
+    title: String,
+    href: String,
+    older_href: Option<String>,
+    newer_href: Option<String>,
+}
+
+/// Pager links rendered above a post.
+struct PostNav<'a> {
+    older_href: Option<&'a str>,
+    newer_href: Option<&'a str>,
+    series: Option<&'a SeriesNav>,
+    /// Slide deck of a TMIL post, linked next to the video.
+    slides_href: Option<&'a str>,
+    /// PDF edition of the post.
+    pdf_href: Option<&'a str>,
+}
+
+/// What a post page shows below its navigation.
+struct PostContent<'a> {
+    body: &'a str,
+    /// Semantic HTML rendering offered next to an SVG `body`.
+    text_body: Option<&'a str>,
+    /// Changes since the previous TMIL's roadmap, for TMIL posts.
+    roadmap_changes: Option<&'a [RoadmapChange]>,
+}
+
+/// Files every post depends on, used for fingerprints when `typst --deps` is unavailable.
+pub const TYPST_SHARED_SOURCES: [&str; 2] =
+    ["content/blog/components.typ", "content/blog/tmil.typ"];
+
 pub fn compile_blog_body(source_path: &str) -> String {
-    // 1. Run the CLI: typst compile <path> --format html -
-    // The "-" at the end tells typst to output to stdout instead of a file
-    let output = Command::new("typst")
-        .arg("compile")
-        .arg(source_path)
-        .arg("--root")
-        .arg(".")
-        .arg("--format")
-        .arg("html")
-        .arg("--features")
-        .arg("html")
-        .arg("-")
-        .output()
-        .expect("Typst CLI not found. Install it with 'cargo install typst-cli'");
-
-    if !output.status.success() {
-        let err = String::from_utf8_lossy(&output.stderr);
-        panic!("Typst Error in {}: {}", source_path, err);
-    }
-
-    let full_html = String::from_utf8_lossy(&output.stdout).to_string();
-
-    // 2. Extract the inner body
-    extract_body(full_html)
+    try_compile_blog_body(source_path)
+        .map(|(body, _)| body)
+        .unwrap_or_else(|err| panic!("Typst Error in {}: {}", source_path, err))
 }
 
-fn generate_svg_sidecar(stem: &str, source_path: &Path) -> Option<String> {
-    let sidecar_dir = Path::new("output/assets/blog/.cache");
+/// Compile a post to semantic HTML, also returning every file Typst read.
+fn try_compile_blog_body(source_path: &str) -> Result<(String, Vec<PathBuf>), String> {
+    let compiled = default_backend().compile(Path::new(source_path), OutputFormat::Html)?;
+    Ok((extract_body(compiled.output), compiled.deps))
+}
+
+fn generate_svg_sidecar(stem: &str, source_path: &Path, deps: &[PathBuf]) -> Option<String> {
+    let sidecar_dir = Path::new(SIDECAR_CACHE_DIR);
     let _ = fs::create_dir_all(sidecar_dir);
-    let fingerprint = blog_sidecar_fingerprint(source_path)?;
-    let sidecar_path = format!("output/assets/blog/.cache/{stem}-{fingerprint:016x}.svg");
+    let fingerprint = typst_fingerprint(source_path, deps)?;
+    let sidecar_path = format!("{SIDECAR_CACHE_DIR}/{stem}-{fingerprint:016x}.svg");
     let sidecar = Path::new(&sidecar_path);
 
     let valid_cached_sidecar = fs::read_to_string(sidecar)
@@ -61,33 +141,84 @@ fn generate_svg_sidecar(stem: &str, source_path: &Path) -> Option<String> {
         return Some(sidecar_path);
     }
 
-    let output = Command::new("tinymist")
-        .arg("compile")
-        .arg(source_path)
-        .arg(&sidecar_path)
-        .arg("--root")
-        .arg(".")
-        .arg("--format")
-        .arg("svg")
-        .output()
-        .ok()?;
+    match default_backend().compile(source_path, OutputFormat::Svg) {
+        Ok(compiled) => {
+            fs::write(sidecar, compiled.output).ok()?;
+            Some(sidecar_path)
+        }
+        Err(err) => {
+            eprintln!("No SVG sidecar for {stem}:\n{err}");
+            None
+        }
+    }
+}
 
-    if !output.status.success() {
-        return None;
+/// Whether a post carries its text in every site language: TMIL items are
+/// written with `tmil_tr`, so they also get one PDF edition per language.
+fn is_localized(stem: &str) -> bool {
+    stem.ends_with("_tmil")
+}
+
+/// Site path of the PDF edition of the post `slug`: `/blog/<slug>.pdf`, or
+/// `/blog/<slug>.<lang>.pdf` for the edition in one language only.
+fn post_pdf_site_path(slug: &str, lang_code: Option<&str>) -> String {
+    match lang_code {
+        Some(lang_code) => format!("/blog/{slug}.{lang_code}.pdf"),
+        None => format!("/blog/{slug}.pdf"),
     }
+}
 
-    Some(sidecar_path)
+/// Where a PDF edition of the post `slug` is published.
+fn post_pdf_path(slug: &str, lang_code: Option<&str>) -> PathBuf {
+    Path::new("output").join(post_pdf_site_path(slug, lang_code).trim_start_matches('/'))
+}
+
+/// Site path and size of the PDF a reader of `lang_code` gets for the post
+/// `slug`: its edition in that language when there is one, else the full
+/// post. `None` until a build has exported it.
+pub fn post_pdf(slug: &str, lang_code: &str) -> Option<(String, u64)> {
+    [Some(lang_code), None].into_iter().find_map(|lang| {
+        let size = fs::metadata(post_pdf_path(slug, lang)).ok()?.len();
+        Some((post_pdf_site_path(slug, lang), size))
+    })
 }
 
-fn blog_sidecar_fingerprint(source_path: &Path) -> Option<u64> {
-    let mut hasher = DefaultHasher::new();
-    source_path.to_string_lossy().hash(&mut hasher);
-    fs::read(source_path).ok()?.hash(&mut hasher);
-    fs::read("content/blog/components.typ")
-        .ok()?
-        .hash(&mut hasher);
-    fs::read("content/blog/tmil.typ").ok()?.hash(&mut hasher);
-    Some(hasher.finish())
+/// Export a post to `output/blog/<slug>.pdf`, or with `lang_code` to its
+/// edition in that language only, unless it was already exported from the
+/// same fingerprint. Returns the cache stamp recording that export.
+fn generate_post_pdf(
+    cache_key: &str,
+    slug: &str,
+    source_path: &Path,
+    deps: &[PathBuf],
+    lang_code: Option<&str>,
+) -> Option<PathBuf> {
+    let fingerprint = typst_fingerprint(source_path, deps)?;
+    let edition = lang_code.map_or(String::new(), |lang_code| format!(".{lang_code}"));
+    let stamp = Path::new(SIDECAR_CACHE_DIR)
+        .join(format!("{cache_key}{edition}.pdf-{fingerprint:016x}.stamp"));
+    let pdf_path = post_pdf_path(slug, lang_code);
+    if stamp.exists() && pdf_path.exists() {
+        return Some(stamp);
+    }
+
+    let inputs: Vec<(&str, &str)> = lang_code.map(|lang| ("lang", lang)).into_iter().collect();
+    match default_backend().compile_pdf(source_path, &inputs) {
+        Ok(pdf) => {
+            if let Some(parent) = pdf_path.parent() {
+                let _ = fs::create_dir_all(parent);
+            }
+            let _ = fs::create_dir_all(SIDECAR_CACHE_DIR);
+            fs::write(&pdf_path, pdf).ok()?;
+            fs::write(&stamp, "").ok()?;
+            Some(stamp)
+        }
+        Err(err) => {
+            // A PDF from an earlier build stays published.
+            eprintln!("No PDF for {cache_key}{edition}:\n{err}");
+            None
+        }
+    }
 }
 
 fn extract_first_block(input: &str, start_marker: &str, end_marker: &str) -> Option<String> {
@@ -129,12 +260,13 @@ fn extract_balanced_svg(input: &str, start: usize) -> Option<String> {
 
 fn extract_typst_doc_svg(input: &str) -> Option<String> {
     if let Some(app_start) = input.find("<div id=\"typst-app\"")
-        && let Some(svg_rel_start) = input[app_start..].find("<svg") {
-            let svg_start = app_start + svg_rel_start;
-            if let Some(svg) = extract_balanced_svg(input, svg_start) {
-                return Some(svg);
-            }
+        && let Some(svg_rel_start) = input[app_start..].find("<svg")
+    {
+        let svg_start = app_start + svg_rel_start;
+        if let Some(svg) = extract_balanced_svg(input, svg_start) {
+            return Some(svg);
         }
+    }
 
     // Support static Tinymist HTML exports where the document SVG is emitted directly.
     if let Some(svg_start) = input.find("<svg class=\"typst-doc\"") {
@@ -144,81 +276,210 @@ fn extract_typst_doc_svg(input: &str) -> Option<String> {
     None
 }
 
-fn tinymist_native_html(sidecar_path: &str, stem: &str) -> Option<String> {
+/// Split a sidecar into its resources block and laid-out document SVG, with
+/// embedded photos moved to files under `images_dir`, linked as `images_href`.
+fn read_tinymist_sidecar(
+    sidecar_path: &str,
+    images_dir: &Path,
+    images_href: &str,
+) -> Option<(String, String)> {
     let raw = fs::read_to_string(sidecar_path).ok()?;
+    let raw = externalize_images(&raw, images_dir, images_href)
+        .unwrap_or_else(|e| panic!("Failed to extract images from {sidecar_path}: {e}"));
     let resources = extract_first_block(&raw, "<svg id=\"typst-svg-resources\"", "</svg>")
         .or_else(|| extract_first_block(&raw, "<svg class=\"typst-svg-resources\"", "</svg>"))
         .unwrap_or_default();
-    let doc_svg = extract_typst_doc_svg(&raw)?;
+    // Page layout happens here at build time, so the post renders without JavaScript.
+    let doc_svg = layout_typst_svg(&extract_typst_doc_svg(&raw)?);
+    Some((resources, doc_svg))
+}
 
-    Some(format!(
-        r##"<div class="blog_post_embed">
+fn tinymist_native_html(resources: &str, doc_svg: &str, stem: &str) -> String {
+    format!(
+        r#"<div class="blog_post_embed">
   <div class="tinymist-native" id="tinymist-native-{stem}">
     {resources}
     {doc_svg}
   </div>
-</div>
-<script>
-(function() {{
-  const root = document.getElementById("tinymist-native-{stem}");
-  if (!root) {{
-    return;
-  }}
-  const svg = root.querySelector("svg.typst-doc");
-  if (!svg) {{
-    return;
-  }}
-
-  const pages = Array.from(svg.querySelectorAll("g.typst-page"));
-  let y = 0;
-  const pageGap = 10;
-  for (const page of pages) {{
-    let targetY = y;
-    let advance = Number(page.getAttribute("data-page-height")) || 0;
-    try {{
-      // Measure only page content so pages collapse without huge blank bands.
-      const content = page.querySelector("g.typst-group") || page;
-      const box = content.getBBox();
-      if (box.height > 0) {{
-        targetY = y - box.y;
-        advance = box.height + pageGap;
-      }}
-    }} catch (_err) {{
-      // Keep data-page-height fallback when geometry isn't measurable.
-    }}
-    page.setAttribute("transform", `translate(0, ${{targetY}})`);
-    y += advance;
-  }}
-
-  const width = Number(svg.getAttribute("data-width"))
-    || Number((pages[0] && pages[0].getAttribute("data-page-width")) || 596);
-  if (width > 0 && y > 0) {{
-    svg.setAttribute("viewBox", `0 0 ${{width}} ${{y}}`);
-  }}
-
-  svg.removeAttribute("height");
-  svg.setAttribute("width", "100%");
-  svg.style.height = "auto";
-  svg.style.display = "block";
-  svg.style.maxWidth = "100%";
-  svg.style.background = "transparent";
-
-  for (const outer of svg.querySelectorAll(".typst-page-outer")) {{
-    outer.remove();
-  }}
-  for (const inner of svg.querySelectorAll(".typst-page-inner")) {{
-    inner.remove();
-  }}
-  for (const ind of svg.querySelectorAll(".typst-preview-canvas-page-number, .typst-preview-svg-page-number")) {{
-    ind.remove();
-  }}
-
-}})();
-</script>"##
-    ))
+</div>"#
+    )
+}
+
+/// Post bodies rendered from Tinymist SVG sidecars, keyed by cache key.
+/// Built once per run so every language shares the same glyph sprite.
+pub struct BlogSidecars {
+    embeds: HashMap<String, String>,
+    /// Typst's semantic HTML of the same posts, offered next to the SVG.
+    texts: HashMap<String, String>,
+    /// Files each post read, as reported by Typst.
+    deps: HashMap<String, Vec<PathBuf>>,
+    /// Slide deck pages of the TMIL posts, see `generate_slide_decks`.
+    decks: Vec<OutputPage>,
+}
+
+impl BlogSidecars {
+    pub fn with_decks(self, decks: Vec<OutputPage>) -> Self {
+        Self { decks, ..self }
+    }
+
+    pub fn decks(&self) -> &[OutputPage] {
+        &self.decks
+    }
+
+    /// Files the post with `cache_key` depends on, if Typst could compile it.
+    pub fn deps(&self, cache_key: &str) -> Option<&[PathBuf]> {
+        self.deps.get(cache_key).map(Vec::as_slice)
+    }
+
+    /// A placeholder body for every post, and a text view holding the
+    /// by-line Typst writes, so pages render without Typst.
+    #[cfg(test)]
+    pub fn placeholders() -> Self {
+        let posts = get_blog_posts();
+        let key = |post: &BlogPost| post.slug.replace('/', "__");
+        let embeds = posts
+            .iter()
+            .map(|post| (key(post), "<svg></svg>".to_string()))
+            .collect();
+        let texts = posts
+            .iter()
+            .filter_map(|post| {
+                let author = post.author.as_ref()?;
+                let email = author.email.as_ref()?;
+                Some((key(post), format!("<p>{} | {email}</p>", author.name)))
+            })
+            .collect();
+        Self {
+            embeds,
+            texts,
+            deps: HashMap::new(),
+            decks: Vec::new(),
+        }
+    }
+}
+
+/// Render every post's sidecar, moving glyph outlines shared across posts into
+/// one fingerprinted sprite under `output/assets/blog/`.
+pub fn prepare_blog_sidecars(site: &SiteConfig) -> BlogSidecars {
+    let assets_href = site.href(BLOG_ASSETS_PUBLIC_DIR);
+    let mut files = Vec::new();
+    collect_blog_post_files(Path::new(BLOG_POSTS_ROOT), &mut files);
+    files.sort();
+
+    let mut glyphs = BTreeMap::new();
+    let mut texts = HashMap::new();
+    let mut post_deps = HashMap::new();
+    let mut referenced = HashSet::new();
+    let mut docs = Vec::new();
+    for file_path in files {
+        let Some(stem) = file_path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        if should_skip_blog_post(stem) {
+            continue;
+        }
+        let slug = slug_from_path(&file_path).unwrap_or_else(|| stem.to_string());
+        let cache_key = slug.replace('/', "__");
+
+        // The SVG alone is unreadable to assistive technology; keep going
+        // without the text view if Typst cannot render this post to HTML.
+        let deps = match try_compile_blog_body(&file_path.to_string_lossy()) {
+            Ok((text, deps)) => {
+                // Typst inlines photos in full; serve them resized instead.
+                let text = responsive_inline_images(site, &text, &cache_key);
+                texts.insert(cache_key.clone(), text);
+                post_deps.insert(cache_key.clone(), deps.clone());
+                deps
+            }
+            Err(err) => {
+                eprintln!("No text view for {cache_key}: {err}");
+                TYPST_SHARED_SOURCES.iter().map(PathBuf::from).collect()
+            }
+        };
+
+        let mut editions = vec![None];
+        if is_localized(stem) {
+            editions.extend(
+                site.languages
+                    .iter()
+                    .map(|lang_code| Some(lang_code.as_str())),
+            );
+        }
+        for lang_code in editions {
+            if let Some(stamp) = generate_post_pdf(&cache_key, &slug, &file_path, &deps, lang_code)
+            {
+                referenced.insert(stamp);
+            }
+        }
+
+        let Some(sidecar_path) = generate_svg_sidecar(&cache_key, &file_path, &deps) else {
+            continue;
+        };
+        referenced.insert(PathBuf::from(&sidecar_path));
+        let Some((resources, doc_svg)) =
+            read_tinymist_sidecar(&sidecar_path, Path::new(BLOG_ASSETS_DIR), &assets_href)
+        else {
+            continue;
+        };
+        let inline_size = resources.len() + doc_svg.len();
+        let (resources, resource_glyphs) = split_glyphs(&resources);
+        let (doc_svg, doc_glyphs) = split_glyphs(&doc_svg);
+        glyphs.extend(resource_glyphs.into_iter().chain(doc_glyphs));
+        docs.push((cache_key, resources, doc_svg, inline_size));
+    }
+
+    // Without any sidecar (e.g. Tinymist missing) keep the cache for the next build.
+    if !referenced.is_empty() {
+        match collect_garbage(&referenced) {
+            Ok((0, _)) => {}
+            Ok((files, bytes)) => println!(
+                "Removed {files} stale sidecar cache entries ({} KiB)",
+                bytes / 1024
+            ),
+            Err(err) => eprintln!("Sidecar cache cleanup failed: {err}"),
+        }
+    }
+
+    let mut embeds = HashMap::new();
+    if docs.is_empty() {
+        return BlogSidecars {
+            embeds,
+            texts,
+            deps: post_deps,
+            decks: Vec::new(),
+        };
+    }
+
+    let sprite_href = write_glyph_sprite(&glyphs, Path::new(BLOG_ASSETS_DIR), &assets_href)
+        .expect("Failed to write the blog glyph sprite");
+    println!("Glyph sprite {sprite_href}: {} shared glyphs", glyphs.len());
+
+    for (cache_key, resources, doc_svg, inline_size) in docs {
+        let resources = link_glyphs(&resources, &sprite_href, &glyphs);
+        let doc_svg = link_glyphs(&doc_svg, &sprite_href, &glyphs);
+        let embed = tinymist_native_html(&resources, &doc_svg, &cache_key);
+        println!(
+            "  {cache_key}: {} KiB -> {} KiB",
+            inline_size / 1024,
+            embed.len() / 1024
+        );
+        // Only formats `externalize_images` cannot name stay inline past the limit.
+        let largest = largest_inline_image(&embed);
+        if largest > INLINE_IMAGE_LIMIT {
+            eprintln!("  {cache_key} still inlines a {largest}-byte data:image payload");
+        }
+        embeds.insert(cache_key, embed);
+    }
+
+    BlogSidecars {
+        embeds,
+        texts,
+        deps: post_deps,
+        decks: Vec::new(),
+    }
 }
 
-fn extract_body(full_html: String) -> String {
+pub fn extract_body(full_html: String) -> String {
     if let (Some(start), Some(end)) = (full_html.find("<body>"), full_html.rfind("</body>")) {
         full_html[start + 6..end].trim().to_string()
     } else {
@@ -226,19 +487,11 @@ fn extract_body(full_html: String) -> String {
     }
 }
 
-fn lang_suffix(lang: &str) -> &str {
-    match lang {
-        "en" => "",
-        "pt-br" => ".pt-br",
-        _ => ".zh",
-    }
-}
-
-fn should_skip_blog_post(stem: &str) -> bool {
+pub fn should_skip_blog_post(stem: &str) -> bool {
     stem == "0000_template" || stem.ends_with("_template")
 }
 
-fn collect_blog_post_files(dir: &Path, files: &mut Vec<PathBuf>) {
+pub fn collect_blog_post_files(dir: &Path, files: &mut Vec<PathBuf>) {
     let Ok(entries) = fs::read_dir(dir) else {
         return;
     };
@@ -255,13 +508,23 @@ fn collect_blog_post_files(dir: &Path, files: &mut Vec<PathBuf>) {
     }
 }
 
-fn slug_from_path(file_path: &Path) -> Option<String> {
+/// `(year, month)` of a `YYYY_MM_tmil` post stem.
+pub fn tmil_month(stem: &str) -> Option<(u32, u32)> {
+    let rest = stem.strip_suffix("_tmil")?;
+    let (year, month) = rest.split_once('_')?;
+    if year.len() != 4 || month.len() != 2 {
+        return None;
+    }
+    Some((year.parse().ok()?, month.parse().ok()?))
+}
+
+pub fn slug_from_path(file_path: &Path) -> Option<String> {
     let rel = file_path.strip_prefix(BLOG_POSTS_ROOT).ok()?;
     let no_ext = rel.with_extension("");
     Some(no_ext.to_string_lossy().replace('\\', "/"))
 }
 
-fn extract_parenthesized_block(input: &str, marker: &str) -> Option<String> {
+pub fn extract_parenthesized_block(input: &str, marker: &str) -> Option<String> {
     let start = input.find(marker)? + marker.len();
     let mut depth = 1usize;
     let mut in_string = false;
@@ -357,7 +620,7 @@ fn parse_tmil_post_title_call(value: &str, mdate: Option<(u32, u32, u32)>) -> Op
     } else {
         parts.get(1)?.parse::<u32>().ok()?
     };
-    Some(format!("This Month in Lince | {year:04}-{month:02}"))
+    Some(format!("{TMIL_TITLE_PREFIX} | {year:04}-{month:02}"))
 }
 
 fn parse_tmil_post_date_call(value: &str, mdate: Option<(u32, u32, u32)>) -> Option<String> {
@@ -499,6 +762,80 @@ fn extract_post_metadata(file_path: &str) -> BlogMetadata {
                     Some(trimmed.to_string())
                 }
             }),
+        series: extract_named_string(&post_args, "series")
+            .map(|s| slugify(&s))
+            .filter(|s| !s.is_empty()),
+        author: extract_post_author(&content, &post_args),
+    }
+}
+
+fn extract_post_author(content: &str, post_args: &str) -> Option<PostAuthor> {
+    let name = extract_let_string(content, "author_name")
+        .or_else(|| extract_named_string(post_args, "name"))
+        .map(|n| n.trim().to_string())
+        .filter(|n| !n.is_empty())?;
+    let email = extract_let_string(content, "author_email")
+        .or_else(|| extract_named_string(post_args, "email"))
+        .map(|e| e.trim().to_string())
+        .filter(|e| !e.is_empty());
+    Some(PostAuthor { name, email })
+}
+
+/// Normalize a name into the slug used for series and author pages.
+pub fn slugify(name: &str) -> String {
+    let mut slug = String::new();
+    for ch in name.trim().to_lowercase().chars() {
+        if ch.is_alphanumeric() {
+            slug.push(ch);
+        } else if !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_matches('-').to_string()
+}
+
+/// Explicit `series:` metadata wins; TMIL files are implied by their `_tmil` stem.
+fn post_series(stem: &str, meta: &BlogMetadata) -> Option<String> {
+    meta.series
+        .clone()
+        .or_else(|| stem.ends_with("_tmil").then(|| TMIL_SERIES.to_string()))
+}
+
+fn series_title(t: &Translations, series: &str) -> String {
+    if series == TMIL_SERIES {
+        t.blog_tmil_title.to_string()
+    } else {
+        series_display_name(series)
+    }
+}
+
+/// A `series:` slug as readers see it: `rust-gpui` → `Rust Gpui`.
+fn series_display_name(series: &str) -> String {
+    series
+        .split(['-', '_'])
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// `video_url` of the post `slug`, if it has one.
+pub fn post_video_url(slug: &str) -> Option<String> {
+    extract_post_metadata(&format!("{BLOG_POSTS_ROOT}/{slug}.typ")).video_url
+}
+
+/// Series name on share cards, which are the same in every language.
+fn series_card_name(series: &str) -> String {
+    if series == TMIL_SERIES {
+        TMIL_TITLE_PREFIX.to_string()
+    } else {
+        series_display_name(series)
     }
 }
 
@@ -524,33 +861,69 @@ fn extract_title_from_typst(file_path: &str) -> String {
 }
 
 fn build_blog_neighbors(
-    ordered_posts: &[(String, String, String)],
+    ordered_posts: &[BlogPost],
 ) -> HashMap<String, (Option<String>, Option<String>)> {
     let mut neighbors: HashMap<String, (Option<String>, Option<String>)> = HashMap::new();
-    for (idx, (slug, _, _)) in ordered_posts.iter().enumerate() {
+    for (idx, post) in ordered_posts.iter().enumerate() {
         let newer = if idx > 0 {
-            Some(ordered_posts[idx - 1].0.clone())
+            Some(ordered_posts[idx - 1].slug.clone())
         } else {
             None
         };
-        let older = ordered_posts.get(idx + 1).map(|p| p.0.clone());
-        neighbors.insert(slug.clone(), (older, newer));
+        let older = ordered_posts.get(idx + 1).map(|p| p.slug.clone());
+        neighbors.insert(post.slug.clone(), (older, newer));
     }
     neighbors
 }
 
-fn render_blog_nav_script(older_href: Option<&str>, newer_href: Option<&str>) -> String {
-    let older = older_href
-        .map(|s| format!("\"{s}\""))
-        .unwrap_or_else(|| "null".to_string());
-    let newer = newer_href
+/// Same as `build_blog_neighbors`, but only stepping between posts of one series.
+fn build_series_neighbors(
+    ordered_posts: &[BlogPost],
+) -> HashMap<String, (Option<String>, Option<String>)> {
+    let mut neighbors = HashMap::new();
+    for series in series_names(ordered_posts) {
+        let entries: Vec<BlogPost> = ordered_posts
+            .iter()
+            .filter(|p| p.series.as_deref() == Some(series.as_str()))
+            .cloned()
+            .collect();
+        neighbors.extend(build_blog_neighbors(&entries));
+    }
+    neighbors
+}
+
+fn series_names(posts: &[BlogPost]) -> Vec<String> {
+    let mut names: Vec<String> = posts.iter().filter_map(|p| p.series.clone()).collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+fn js_string_or_null(value: Option<&str>) -> String {
+    value
         .map(|s| format!("\"{s}\""))
-        .unwrap_or_else(|| "null".to_string());
+        .unwrap_or_else(|| "null".to_string())
+}
+
+/// Arrow keys step through all posts; `[` and `]` step within the series.
+/// Shift + arrow keys are left alone: browsers use them to extend a selection.
+fn render_blog_nav_script(
+    older_href: Option<&str>,
+    newer_href: Option<&str>,
+    series_older_href: Option<&str>,
+    series_newer_href: Option<&str>,
+) -> String {
+    let older = js_string_or_null(older_href);
+    let newer = js_string_or_null(newer_href);
+    let series_older = js_string_or_null(series_older_href);
+    let series_newer = js_string_or_null(series_newer_href);
 
     format!(
         r#"(function() {{
   const olderUrl = {older};
   const newerUrl = {newer};
+  const seriesOlderUrl = {series_older};
+  const seriesNewerUrl = {series_newer};
   const shouldIgnore = (el) => {{
     if (!el) return false;
     const tag = (el.tagName || "").toLowerCase();
@@ -559,10 +932,14 @@ fn render_blog_nav_script(older_href: Option<&str>, newer_href: Option<&str>) ->
   window.addEventListener("keydown", (ev) => {{
     if (ev.defaultPrevented || ev.altKey || ev.ctrlKey || ev.metaKey || ev.shiftKey) return;
     if (shouldIgnore(document.activeElement)) return;
-    if (ev.key === "ArrowLeft" && olderUrl) {{
-      window.location.href = olderUrl;
-    }} else if (ev.key === "ArrowRight" && newerUrl) {{
-      window.location.href = newerUrl;
+    const target = {{
+      ArrowLeft: olderUrl,
+      ArrowRight: newerUrl,
+      "[": seriesOlderUrl,
+      "]": seriesNewerUrl,
+    }}[ev.key];
+    if (target) {{
+      window.location.href = target;
     }}
   }});
 }})();"#,
@@ -570,7 +947,7 @@ fn render_blog_nav_script(older_href: Option<&str>, newer_href: Option<&str>) ->
 }
 
 /// Get all blog posts with their metadata
-pub fn get_blog_posts() -> Vec<(String, String, String)> {
+pub fn get_blog_posts() -> Vec<BlogPost> {
     let mut posts = Vec::new();
     let mut files = Vec::new();
     collect_blog_post_files(Path::new(BLOG_POSTS_ROOT), &mut files);
@@ -586,22 +963,45 @@ pub fn get_blog_posts() -> Vec<(String, String, String)> {
             continue;
         };
         let meta = extract_post_metadata(path_str);
+        let series = post_series(stem, &meta);
+        let author = meta.author.clone();
         let title = meta
             .title
             .unwrap_or_else(|| extract_title_from_typst(path_str));
         let date = meta.date.unwrap_or_default();
         let slug = slug_from_path(&file_path).unwrap_or_else(|| stem.to_string());
-        posts.push((slug, title, date));
+        posts.push(BlogPost {
+            slug,
+            title,
+            date,
+            series,
+            author,
+            kind: PostKind::Typst,
+        });
     }
 
+    let reviews = review_posts(&posts);
+    posts.extend(reviews);
+
     // Always sort reverse alphabetically by lowercase slug (latest first).
-    posts.sort_by(|a, b| b.0.to_lowercase().cmp(&a.0.to_lowercase()));
+    posts.sort_by_key(|p| std::cmp::Reverse(p.slug.to_lowercase()));
     posts
 }
-pub fn generate_blog_posts(t: &Translations, suffix: &str, show_home: bool) {
-    let blog_href = format!("/blog{}.html", suffix);
+/// Every post's page in the language of `t`.
+pub fn blog_post_pages(
+    t: &Translations,
+    site: &SiteConfig,
+    profiles: &HashMap<String, AuthorProfile>,
+    sidecars: &BlogSidecars,
+    show_home: bool,
+    render_card: CardRenderer,
+) -> Vec<OutputPage> {
+    let mut pages = Vec::new();
+    let blog_href = Route::Blog.href(site, t.lang_code);
     let ordered_posts = get_blog_posts(); // latest first
     let neighbors = build_blog_neighbors(&ordered_posts);
+    let series_neighbors = build_series_neighbors(&ordered_posts);
+    let roadmaps = tmil_roadmaps();
 
     let mut files = Vec::new();
     collect_blog_post_files(Path::new(BLOG_POSTS_ROOT), &mut files);
@@ -626,107 +1026,415 @@ pub fn generate_blog_posts(t: &Translations, suffix: &str, show_home: bool) {
         let (older_slug, newer_slug) = neighbors.get(&slug).cloned().unwrap_or((None, None));
         let older_href = older_slug
             .as_ref()
-            .map(|older| format!("/blog/{}{}.html", older, suffix));
+            .map(|older| Route::BlogPost(older).href(site, t.lang_code));
         let newer_href = newer_slug
             .as_ref()
-            .map(|newer| format!("/blog/{}{}.html", newer, suffix));
+            .map(|newer| Route::BlogPost(newer).href(site, t.lang_code));
+        let series_nav = post_series(stem, &meta).map(|series| {
+            let (older, newer) = series_neighbors.get(&slug).cloned().unwrap_or((None, None));
+            SeriesNav {
+                title: series_title(t, &series),
+                href: Route::Series(&series).href(site, t.lang_code),
+                older_href: older.map(|older| Route::BlogPost(&older).href(site, t.lang_code)),
+                newer_href: newer.map(|newer| Route::BlogPost(&newer).href(site, t.lang_code)),
+            }
+        });
+        let author_link = meta.author.as_ref().map(|author| {
+            (
+                author_display_name(profiles, author).to_string(),
+                author_href(site, author, t.lang_code),
+            )
+        });
         let cache_key = slug.replace('/', "__");
+        let slides_href = deck_href(site, sidecars.decks(), stem, t.lang_code);
+        let roadmap_changes = roadmap_changes(&roadmaps, &slug);
+        let pdf_href = post_pdf(&slug, t.lang_code).map(|(path, _)| site.href(&path));
 
         // Prefer Tinymist-rendered sidecar HTML when available.
-        // Fallback to Typst CLI HTML if sidecar parsing fails.
-        let sidecar_path = generate_svg_sidecar(&cache_key, &file_path);
-
-        let body = if let Some(sidecar_path) = sidecar_path {
-            tinymist_native_html(&sidecar_path, &cache_key)
-                .unwrap_or_else(|| compile_blog_body(path_str))
-        } else {
-            compile_blog_body(path_str)
+        // Fallback to Typst HTML if sidecar parsing fails.
+        let (body, text_body) = match sidecars.embeds.get(&cache_key) {
+            Some(embed) => (embed.clone(), sidecars.texts.get(&cache_key).cloned()),
+            None => (compile_blog_body(path_str), None),
+        };
+        // The by-line of `components.typ` prints the author's email, which
+        // Typst HTML keeps as plain text.
+        let (body, text_body) = match meta.author.as_ref().and_then(|a| a.email.as_deref()) {
+            Some(email) => {
+                let hide = |html: String| html.replace(email, &obfuscate_email(email));
+                (hide(body), text_body.map(hide))
+            }
+            None => (body, text_body),
         };
 
-        // Wrap in Maud with breadcrumbs
-        let markup = html! {
-            main.main-content.blog-post-content {
-                nav.breadcrumbs.blog-breadcrumbs {
-                    a.blog-back-link href=(blog_href.clone()) { (t.blog_back_to_posts) }
-                    @if let Some(video_url) = &meta.video_url {
-                        a.blog-video-link href=(video_url) target="_blank" rel="noopener noreferrer" {
-                            (t.blog_watch_video)
-                        }
+        let title = meta
+            .title
+            .clone()
+            .unwrap_or_else(|| extract_title_from_typst(path_str));
+        let page_title = localized_post_title(t, &title);
+        let video = meta
+            .video_url
+            .as_deref()
+            .map(|url| PostVideo::resolve(site, url, &page_title));
+
+        let markup = render_blog_post(
+            t,
+            site,
+            &blog_href,
+            video.as_ref(),
+            author_link
+                .as_ref()
+                .map(|(name, href)| (name.as_str(), href.as_str())),
+            PostNav {
+                older_href: older_href.as_deref(),
+                newer_href: newer_href.as_deref(),
+                series: series_nav.as_ref(),
+                slides_href: slides_href.as_deref(),
+                pdf_href: pdf_href.as_deref(),
+            },
+            PostContent {
+                body: &body,
+                text_body: text_body.as_deref(),
+                roadmap_changes: roadmap_changes.as_deref(),
+            },
+        );
+        let route = Route::BlogPost(&slug);
+        let kicker = post_series(stem, &meta).map(|series| series_card_name(&series));
+        let share_image = render_card(
+            &route.key(),
+            &ShareCard {
+                title: &title,
+                kicker: kicker.as_deref(),
+                date: meta.date.as_deref(),
+            },
+        );
+        let final_html = page(
+            &markup.0,
+            t,
+            site,
+            route,
+            &page_title,
+            show_home,
+            share_image.as_deref(),
+        );
+        pages.extend(route_pages(site, route, t.lang_code, final_html));
+    }
+    pages
+}
+
+/// The year in review posts, paged between the posts around them.
+pub fn year_review_pages(
+    t: &Translations,
+    site: &SiteConfig,
+    show_home: bool,
+    render_card: CardRenderer,
+) -> Vec<OutputPage> {
+    let mut pages = Vec::new();
+    let blog_href = Route::Blog.href(site, t.lang_code);
+    let ordered_posts = get_blog_posts(); // latest first
+    let neighbors = build_blog_neighbors(&ordered_posts);
+    let roadmaps = tmil_roadmaps();
+
+    for (year, post) in ordered_posts.iter().filter_map(|post| match post.kind {
+        PostKind::Review(year) => Some((year, post)),
+        PostKind::Typst => None,
+    }) {
+        let (older_slug, newer_slug) = neighbors.get(&post.slug).cloned().unwrap_or((None, None));
+        let older_href = older_slug.map(|older| Route::BlogPost(&older).href(site, t.lang_code));
+        let newer_href = newer_slug.map(|newer| Route::BlogPost(&newer).href(site, t.lang_code));
+        let body = render_review(t, site, year, &roadmaps).into_string();
+
+        let markup = render_blog_post(
+            t,
+            site,
+            &blog_href,
+            None,
+            None,
+            PostNav {
+                older_href: older_href.as_deref(),
+                newer_href: newer_href.as_deref(),
+                series: None,
+                slides_href: None,
+                pdf_href: None,
+            },
+            PostContent {
+                body: &body,
+                text_body: None,
+                roadmap_changes: None,
+            },
+        );
+        let route = Route::BlogPost(&post.slug);
+        let share_image = render_card(
+            &route.key(),
+            &ShareCard {
+                title: &post.title,
+                kicker: None,
+                date: Some(&post.date),
+            },
+        );
+        let final_html = page(
+            &markup.0,
+            t,
+            site,
+            route,
+            &review_title(t, year),
+            show_home,
+            share_image.as_deref(),
+        );
+        pages.extend(route_pages(site, route, t.lang_code, final_html));
+    }
+    pages
+}
+
+/// Wrap a rendered post body with breadcrumbs, the older/newer pager and,
+/// for posts in a series, the previous/next-in-series pager.
+fn render_blog_post(
+    t: &Translations,
+    site: &SiteConfig,
+    blog_href: &str,
+    video: Option<&PostVideo>,
+    author: Option<(&str, &str)>,
+    nav: PostNav,
+    content: PostContent,
+) -> Markup {
+    let PostContent {
+        body,
+        text_body,
+        roadmap_changes,
+    } = content;
+    let PostNav {
+        older_href,
+        newer_href,
+        series: series_nav,
+        slides_href,
+        pdf_href,
+    } = nav;
+    let series_older_href = series_nav.and_then(|s| s.older_href.as_deref());
+    let series_newer_href = series_nav.and_then(|s| s.newer_href.as_deref());
+
+    html! {
+        main.main-content.blog-post-content {
+            nav.breadcrumbs.blog-breadcrumbs {
+                a.blog-back-link href=(blog_href) { (t.blog_back_to_posts) }
+                @if let Some((name, href)) = author {
+                    span.blog-author-byline {
+                        (t.blog_by) " "
+                        a.blog-author-link href=(href) { (name) }
                     }
                 }
-                @if older_slug.is_some() || newer_slug.is_some() {
-                    nav.blog-post-pager {
-                        @if let Some(ref older) = older_slug {
-                            a.blog-post-nav-link.blog-post-nav-left href=(format!("/blog/{}{}.html", older, suffix)) { "← Older" }
-                        } @else {
-                            span.blog-post-nav-link.blog-post-nav-left.disabled { "← Older" }
-                        }
-                        @if let Some(ref newer) = newer_slug {
-                            a.blog-post-nav-link.blog-post-nav-right href=(format!("/blog/{}{}.html", newer, suffix)) { "Newer →" }
-                        } @else {
-                            span.blog-post-nav-link.blog-post-nav-right.disabled { "Newer →" }
-                        }
+                @if let Some(video) = video {
+                    a.blog-video-link href=(video.url()) target="_blank" rel="noopener noreferrer" {
+                        (t.blog_watch_video)
+                    }
+                }
+                @if let Some(href) = slides_href {
+                    a.blog-slides-link href=(href) { (t.blog_slides) }
+                }
+                @if let Some(href) = pdf_href {
+                    a.blog-pdf-link href=(href) type="application/pdf" { (t.blog_pdf) }
+                }
+            }
+            @if older_href.is_some() || newer_href.is_some() {
+                nav.blog-post-pager {
+                    @if let Some(older) = older_href {
+                        a.blog-post-nav-link.blog-post-nav-left href=(older) { (t.blog_older) }
+                    } @else {
+                        span.blog-post-nav-link.blog-post-nav-left.disabled { (t.blog_older) }
+                    }
+                    @if let Some(newer) = newer_href {
+                        a.blog-post-nav-link.blog-post-nav-right href=(newer) { (t.blog_newer) }
+                    } @else {
+                        span.blog-post-nav-link.blog-post-nav-right.disabled { (t.blog_newer) }
+                    }
+                }
+            }
+            @if let Some(series) = series_nav {
+                nav.blog-post-pager.blog-series-pager {
+                    @if let Some(older) = series_older_href {
+                        a.blog-post-nav-link.blog-post-nav-left href=(older) { (t.blog_series_previous) }
+                    } @else {
+                        span.blog-post-nav-link.blog-post-nav-left.disabled { (t.blog_series_previous) }
                     }
-                    script {
-                        (PreEscaped(render_blog_nav_script(
-                            older_href.as_deref(),
-                            newer_href.as_deref(),
-                        )))
+                    a.blog-series-link href=(series.href) { (series.title) }
+                    @if let Some(newer) = series_newer_href {
+                        a.blog-post-nav-link.blog-post-nav-right href=(newer) { (t.blog_series_next) }
+                    } @else {
+                        span.blog-post-nav-link.blog-post-nav-right.disabled { (t.blog_series_next) }
                     }
                 }
+            }
+            @if older_href.is_some() || newer_href.is_some() || series_nav.is_some() {
+                script {
+                    (PreEscaped(render_blog_nav_script(
+                        older_href,
+                        newer_href,
+                        series_older_href,
+                        series_newer_href,
+                    )))
+                }
+            }
+            @if let Some(video) = video {
+                (render_video_facade(t, site, video))
+            }
+            @if let Some(text) = text_body {
+                div.blog-view-toggle role="group" aria-label=(t.blog_view_label) {
+                    button.blog-view-toggle__option type="button" data-post-view="faithful"
+                        onclick="setPostView('faithful')" { (t.blog_view_faithful) }
+                    button.blog-view-toggle__option type="button" data-post-view="text"
+                        onclick="setPostView('text')" { (t.blog_view_text) }
+                }
+                article.blog_post {
+                    div.blog-post-faithful aria-hidden="true" { (PreEscaped(body)) }
+                    div.blog-post-text { (PreEscaped(text)) }
+                }
+            } @else {
                 article.blog_post { (PreEscaped(body)) }
             }
-        };
-        let blog_post_page = format!("blog/{}", slug);
-        let final_html = page(&markup.0, t, &blog_post_page, show_home);
-        let output_path = format!("output/blog/{}{}.html", slug, suffix);
-        if let Some(parent) = Path::new(&output_path).parent() {
-            let _ = fs::create_dir_all(parent);
+            @if let Some(changes) = roadmap_changes {
+                (render_roadmap_changes(t, site, changes))
+            }
         }
-        fs::write(output_path, final_html).unwrap();
     }
 }
 
-pub fn page_blog(t: &Translations) -> String {
-    let suffix = lang_suffix(t.lang_code);
+/// Swap the English TMIL title prefix for the localized one.
+pub fn localized_post_title(t: &Translations, title: &str) -> String {
+    match title.strip_prefix(TMIL_TITLE_PREFIX) {
+        Some(rest) => format!("{}{}", t.blog_tmil_title, rest),
+        None => title.to_string(),
+    }
+}
+
+pub fn page_blog(
+    t: &Translations,
+    site: &SiteConfig,
+    profiles: &HashMap<String, AuthorProfile>,
+) -> String {
     let posts = get_blog_posts();
 
     html! {
         main.main-content {
             section.blog-header {
                 h1.section-title { (t.blog_title) }
+                @if site.sections.roadmap {
+                    a.blog-roadmap-link href=(Route::Roadmap.href(site, t.lang_code)) { (t.roadmap_title) }
+                }
             }
 
             @if posts.is_empty() {
                 section.blog-posts-container {
-                    p.no-posts { "No blog posts yet." }
+                    p.no-posts { (t.blog_no_posts) }
                 }
             } @else {
                 section.blog-posts-container {
                     ul.blog-posts-list {
-                        @for (slug, title, date) in posts {
-                            li.blog-post-item {
-                                a.blog-post-link href=(format!("/blog/{}{}.html", slug, suffix)) {
-                                    h3.blog-post-title { (title) }
-                                    span.blog-post-dots aria-hidden="true" {}
-                                    p.blog-post-date { (date) }
-                                }
-                            }
+                        @for post in &posts {
+                            (render_blog_post_item(t, site, profiles, post))
+                        }
+                    }
+                }
+            }
+        }
+    }
+    .0
+}
+
+pub fn render_blog_post_item(
+    t: &Translations,
+    site: &SiteConfig,
+    profiles: &HashMap<String, AuthorProfile>,
+    post: &BlogPost,
+) -> Markup {
+    html! {
+        li.blog-post-item {
+            a.blog-post-link href=(Route::BlogPost(&post.slug).href(site, t.lang_code)) {
+                h3.blog-post-title { (post.localized_title(t)) }
+                span.blog-post-dots aria-hidden="true" {}
+                p.blog-post-date {
+                    time datetime=(post.date) { (t.format_date(&post.date)) }
+                    @if let Some(author) = &post.author {
+                        span.blog-post-author {
+                            " · " (t.blog_by) " " (author_display_name(profiles, author))
                         }
                     }
                 }
             }
         }
     }
+}
+
+/// Landing page listing every post of one series, oldest first.
+fn page_series(
+    t: &Translations,
+    site: &SiteConfig,
+    profiles: &HashMap<String, AuthorProfile>,
+    series: &str,
+    posts: &[BlogPost],
+) -> String {
+    let blog_href = Route::Blog.href(site, t.lang_code);
+    let entries: Vec<&BlogPost> = posts
+        .iter()
+        .rev()
+        .filter(|p| p.series.as_deref() == Some(series))
+        .collect();
+
+    html! {
+        main.main-content {
+            nav.breadcrumbs.blog-breadcrumbs {
+                a.blog-back-link href=(blog_href) { (t.blog_back_to_posts) }
+            }
+            section.blog-header {
+                h1.section-title { (series_title(t, series)) }
+                p.section-description { (t.blog_series_label) }
+            }
+            section.blog-posts-container {
+                ul.blog-posts-list {
+                    @for post in entries {
+                        (render_blog_post_item(t, site, profiles, post))
+                    }
+                }
+            }
+        }
+    }
     .0
 }
 
+pub fn series_pages(
+    t: &Translations,
+    site: &SiteConfig,
+    profiles: &HashMap<String, AuthorProfile>,
+    show_home: bool,
+) -> Vec<OutputPage> {
+    let posts = get_blog_posts();
+    series_names(&posts)
+        .into_iter()
+        .flat_map(|series| {
+            let route = Route::Series(&series);
+            let content = page_series(t, site, profiles, &series, &posts);
+            let title = series_title(t, &series);
+            let final_html = page(&content, t, site, route, &title, show_home, None);
+            route_pages(site, route, t.lang_code, final_html)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
-        build_blog_neighbors, collect_blog_post_files, extract_mdate, extract_post_metadata,
-        get_blog_posts, render_blog_nav_script,
+        BLOG_ASSETS_PUBLIC_DIR, BlogPost, BlogSidecars, PostAuthor, PostContent, PostKind, PostNav,
+        SeriesNav, TMIL_SERIES, blog_post_pages, build_blog_neighbors, build_series_neighbors,
+        collect_blog_post_files, extract_mdate, extract_post_metadata, get_blog_posts, page_blog,
+        page_series, read_tinymist_sidecar, render_blog_nav_script, render_blog_post, series_pages,
+        series_title, tinymist_native_html, year_review_pages,
     };
+    use crate::config::SiteConfig;
+    use crate::i18n::get_translations;
+    use crate::pages::authors::{author_pages, load_author_profiles};
+    use crate::pages::roadmap::page_roadmap;
+    use crate::pages::video::PostVideo;
+    use crate::pages::visual_identity::page_visual_identity;
+    use crate::roadmap::{ItemState, RoadmapChange};
+    use crate::share_card::ShareCard;
+    use crate::sidecar::{INLINE_IMAGE_LIMIT, largest_inline_image};
     use std::path::{Path, PathBuf};
 
     #[test]
@@ -739,10 +1447,10 @@ mod tests {
 
         let offenders: Vec<String> = posts
             .iter()
-            .filter_map(|(slug, title, _date)| {
-                let stem = Path::new(slug).file_name()?.to_str()?;
-                if title.trim() == stem {
-                    Some(format!("{slug} -> {title}"))
+            .filter_map(|post| {
+                let stem = Path::new(&post.slug).file_name()?.to_str()?;
+                if post.title.trim() == stem {
+                    Some(format!("{} -> {}", post.slug, post.title))
                 } else {
                     None
                 }
@@ -765,14 +1473,15 @@ mod tests {
         );
 
         let neighbors = build_blog_neighbors(&posts);
-        for (idx, (slug, _, _)) in posts.iter().enumerate() {
+        for (idx, post) in posts.iter().enumerate() {
+            let slug = &post.slug;
             let (older, newer) = neighbors
                 .get(slug)
                 .unwrap_or_else(|| panic!("Missing neighbor entry for slug: {slug}"));
 
-            let expected_older = posts.get(idx + 1).map(|p| p.0.clone());
+            let expected_older = posts.get(idx + 1).map(|p| p.slug.clone());
             let expected_newer = if idx > 0 {
-                Some(posts[idx - 1].0.clone())
+                Some(posts[idx - 1].slug.clone())
             } else {
                 None
             };
@@ -819,14 +1528,17 @@ mod tests {
             "Expected at least one blog post when validating metadata"
         );
 
-        for (slug, title, date) in posts {
+        for post in posts {
             assert!(
-                !title.trim().is_empty(),
-                "Post title is empty for slug: {slug}"
+                !post.title.trim().is_empty(),
+                "Post title is empty for slug: {}",
+                post.slug
             );
             assert!(
-                is_valid_iso_date(&date),
-                "Post date is not valid YYYY-MM-DD for slug {slug}: {date}"
+                is_valid_iso_date(&post.date),
+                "Post date is not valid YYYY-MM-DD for slug {}: {}",
+                post.slug,
+                post.date
             );
         }
     }
@@ -876,13 +1588,13 @@ mod tests {
         assert!(!posts.is_empty(), "Expected posts for ordering check");
 
         for pair in posts.windows(2) {
-            let a = pair[0].0.to_lowercase();
-            let b = pair[1].0.to_lowercase();
+            let a = pair[0].slug.to_lowercase();
+            let b = pair[1].slug.to_lowercase();
             assert!(
                 a >= b,
                 "Posts are not sorted latest-first by slug: {} then {}",
-                pair[0].0,
-                pair[1].0
+                pair[0].slug,
+                pair[1].slug
             );
         }
     }
@@ -909,30 +1621,65 @@ mod tests {
         }
     }
 
+    /// Every post page in English, rendered without Typst.
+    fn rendered_post_pages() -> Vec<(String, String)> {
+        let t = &get_translations()["en"];
+        let site = SiteConfig::load().unwrap();
+        let no_card = &|_: &str, _: &ShareCard| None;
+        let mut pages = blog_post_pages(
+            t,
+            &site,
+            &load_author_profiles(),
+            &BlogSidecars::placeholders(),
+            true,
+            no_card,
+        );
+        pages.extend(year_review_pages(t, &site, true, no_card));
+        pages
+    }
+
     #[test]
     fn generated_blog_links_point_to_existing_output_html() {
         let posts = get_blog_posts();
         assert!(!posts.is_empty(), "Expected posts for output-link check");
-        for (slug, _title, _date) in posts {
-            let output = format!("output/blog/{slug}.html");
+        let pages = rendered_post_pages();
+        for post in posts {
+            let output = format!("blog/{}.html", post.slug);
             assert!(
-                Path::new(&output).exists(),
-                "Missing generated blog output file for slug {slug}: {output}"
+                pages.iter().any(|(path, _)| *path == output),
+                "Missing generated blog output file for slug {}: {output}",
+                post.slug
             );
         }
     }
 
+    #[test]
+    fn youtube_videos_keep_their_breadcrumb_link() {
+        let pages = rendered_post_pages();
+        let (_, html) = pages
+            .iter()
+            .find(|(path, _)| path == "blog/2026/2026_02_tmil.html")
+            .expect("2026-02 TMIL is not built");
+        assert!(html.contains("video-facade"));
+        assert!(html.contains(
+            r#"class="blog-video-link" href="https://youtu.be/iH4L37GrPBE?si=yeZowC8tK3VeoS-L""#
+        ));
+    }
+
     #[test]
     fn generated_blog_outputs_are_unique_per_slug() {
         let posts = get_blog_posts();
         assert!(!posts.is_empty(), "Expected posts for uniqueness check");
 
+        let pages = rendered_post_pages();
         let mut rendered = Vec::new();
-        for (slug, _title, _date) in posts {
-            let output = format!("output/blog/{slug}.html");
-            let body = std::fs::read_to_string(&output)
-                .unwrap_or_else(|e| panic!("Failed to read generated output {output}: {e}"));
-            rendered.push((slug, body));
+        for post in posts {
+            let output = format!("blog/{}.html", post.slug);
+            let (_, body) = pages
+                .iter()
+                .find(|(path, _)| *path == output)
+                .unwrap_or_else(|| panic!("Failed to render {output}"));
+            rendered.push((post.slug, body));
         }
 
         for i in 0..rendered.len() {
@@ -948,9 +1695,21 @@ mod tests {
 
     #[test]
     fn blog_nav_script_contains_arrow_key_navigation() {
-        let script = render_blog_nav_script(Some("/blog/older.html"), Some("/blog/newer.html"));
+        let script = render_blog_nav_script(
+            Some("/blog/older.html"),
+            Some("/blog/newer.html"),
+            Some("/blog/series-older.html"),
+            None,
+        );
         assert!(script.contains("ArrowLeft"), "Missing ArrowLeft handler");
         assert!(script.contains("ArrowRight"), "Missing ArrowRight handler");
+        assert!(
+            script.contains(r#""[": seriesOlderUrl"#) && script.contains(r#""]": seriesNewerUrl"#)
+        );
+        assert!(
+            script.contains("ev.shiftKey) return;"),
+            "Shift + arrow keys must keep extending the selection"
+        );
         assert!(
             script.contains("\"/blog/older.html\""),
             "Missing older URL in nav script"
@@ -959,6 +1718,222 @@ mod tests {
             script.contains("\"/blog/newer.html\""),
             "Missing newer URL in nav script"
         );
+        assert!(
+            script.contains("seriesOlderUrl = \"/blog/series-older.html\""),
+            "Missing series older URL in nav script"
+        );
+        assert!(
+            script.contains("seriesNewerUrl = null"),
+            "Missing series newer fallback in nav script"
+        );
+    }
+
+    #[test]
+    fn series_without_a_translation_are_title_cased() {
+        let t = &get_translations()["pt-br"];
+        assert_eq!(series_title(t, "tmil"), t.blog_tmil_title);
+        assert_eq!(series_title(t, "rust-gpui"), "Rust Gpui");
+        assert_eq!(series_title(t, "dev_diary"), "Dev Diary");
+    }
+
+    #[test]
+    fn series_neighbors_skip_posts_outside_the_series() {
+        let post = |slug: &str, series: Option<&str>| BlogPost {
+            slug: slug.to_string(),
+            title: slug.to_string(),
+            date: String::new(),
+            series: series.map(str::to_string),
+            author: None,
+            kind: PostKind::Typst,
+        };
+        let posts = vec![
+            post("2026/2026_05_tmil", Some("tmil")),
+            post("2026/2026_04_release", None),
+            post("2026/2026_04_tmil", Some("tmil")),
+            post("2026/2026_03_diary", Some("diary")),
+            post("2026/2026_03_tmil", Some("tmil")),
+        ];
+
+        let neighbors = build_series_neighbors(&posts);
+        assert_eq!(
+            neighbors["2026/2026_04_tmil"],
+            (
+                Some("2026/2026_03_tmil".to_string()),
+                Some("2026/2026_05_tmil".to_string())
+            )
+        );
+        assert_eq!(neighbors["2026/2026_03_diary"], (None, None));
+        assert!(!neighbors.contains_key("2026/2026_04_release"));
+    }
+
+    #[test]
+    fn post_authors_are_parsed_from_tmil_metadata() {
+        let meta = extract_post_metadata("content/blog/posts/2026/2026_02_tmil.typ");
+        assert_eq!(
+            meta.author,
+            Some(PostAuthor {
+                name: "duds".to_string(),
+                email: Some("xaviduds@gmail.com".to_string()),
+            })
+        );
+        for post in get_blog_posts() {
+            assert!(
+                post.author.is_some() || post.kind != PostKind::Typst,
+                "Post {} has no author",
+                post.slug
+            );
+        }
+    }
+
+    #[test]
+    fn tmil_posts_belong_to_the_tmil_series() {
+        for post in get_blog_posts() {
+            if post.slug.ends_with("_tmil") {
+                assert_eq!(
+                    post.series.as_deref(),
+                    Some(TMIL_SERIES),
+                    "TMIL post {} is missing its implied series",
+                    post.slug
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn dates_are_formatted_per_locale() {
+        let translations = get_translations();
+        for (lang, expected) in [
+            ("en", "April 1, 2026"),
+            ("pt-br", "1 de abril de 2026"),
+            ("zh", "2026年4月1日"),
+        ] {
+            assert_eq!(translations[lang].format_date("2026-04-01"), expected);
+        }
+        assert_eq!(translations["en"].format_date("not-a-date"), "not-a-date");
+    }
+
+    #[test]
+    fn localized_pages_do_not_leak_english_literals() {
+        let translations = get_translations();
+        let profiles = load_author_profiles();
+        let site = SiteConfig::load().unwrap();
+
+        for lang in ["pt-br", "zh"] {
+            let t = &translations[lang];
+            let rendered = [
+                page_blog(t, &site, &profiles),
+                page_visual_identity(t, &site),
+                page_roadmap(t, &site),
+                page_series(t, &site, &profiles, TMIL_SERIES, &get_blog_posts()),
+                render_blog_post(
+                    t,
+                    &site,
+                    "/blog.html",
+                    Some(&PostVideo::resolve(
+                        &site,
+                        "https://youtu.be/iH4L37GrPBE",
+                        "TMIL",
+                    )),
+                    Some(("duds", "/blog/authors/duds.html")),
+                    PostNav {
+                        older_href: Some("/blog/older.html"),
+                        newer_href: Some("/blog/newer.html"),
+                        series: Some(&SeriesNav {
+                            title: "tmil".to_string(),
+                            href: "/blog/series/tmil.html".to_string(),
+                            older_href: None,
+                            newer_href: Some("/blog/newer.html".to_string()),
+                        }),
+                        slides_href: Some("/slides/2026/04.html"),
+                        pdf_href: Some("/blog/2026/2026_04_tmil.pdf"),
+                    },
+                    PostContent {
+                        body: "",
+                        text_body: Some(""),
+                        roadmap_changes: Some(&[
+                            RoadmapChange::Added {
+                                context: Vec::new(),
+                                text: "AI".to_string(),
+                                state: ItemState::Todo,
+                            },
+                            RoadmapChange::Removed {
+                                context: Vec::new(),
+                                text: "Stock".to_string(),
+                            },
+                            RoadmapChange::Moved {
+                                context: vec!["Todo".to_string()],
+                                text: "Kanban".to_string(),
+                                from: ItemState::InProgress,
+                                to: ItemState::Done,
+                            },
+                        ]),
+                    },
+                )
+                .into_string(),
+            ]
+            .join("\n");
+
+            let no_card = &|_: &str, _: &ShareCard| None;
+            let sidecars = BlogSidecars::placeholders();
+            let pages = blog_post_pages(t, &site, &profiles, &sidecars, true, no_card)
+                .into_iter()
+                .chain(year_review_pages(t, &site, true, no_card))
+                .chain(series_pages(t, &site, &profiles, true))
+                .chain(author_pages(t, &site, &profiles, true));
+            let rendered: String = pages.map(|(_, html)| html).chain([rendered]).collect();
+
+            for english in ENGLISH_UI_STRINGS {
+                assert!(
+                    !contains_word(&rendered, english),
+                    "English UI string `{english}` leaked into {lang} output"
+                );
+            }
+        }
+    }
+
+    /// Interface text of the English pages, which no other language may show.
+    const ENGLISH_UI_STRINGS: &[&str] = &[
+        "Back to Blog Posts",
+        "Watch on YouTube",
+        "No blog posts yet.",
+        "← Older",
+        "Newer →",
+        "This Month in Lince",
+        "Previous in series",
+        "Next in series",
+        "All posts in this series, oldest first.",
+        "Contact:",
+        "Play video",
+        "Post view",
+        "Faithful",
+        "Clicking play loads the video",
+        "More on our channel:",
+        "The roadmap as of the latest",
+        "To do",
+        "In progress",
+        "Changed in",
+        "Roadmap changes this month",
+        "The roadmap did not change this month.",
+        "Added:",
+        "Removed:",
+        "How the roadmap evolved",
+        "in Review",
+        "Visual Identity",
+        "Official logo files",
+        "Home",
+        "Download",
+        "Theme",
+        "January",
+        "April",
+        "December",
+    ];
+
+    fn contains_word(haystack: &str, needle: &str) -> bool {
+        haystack.match_indices(needle).any(|(idx, _)| {
+            let before = haystack[..idx].chars().next_back();
+            let after = haystack[idx + needle.len()..].chars().next();
+            !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+        })
     }
 
     #[test]
@@ -1015,12 +1990,12 @@ mod tests {
 
         for line in content.lines() {
             let trimmed = line.trim();
-            if let Some(rest) = trimmed.strip_prefix("photo: \"") {
-                if let Some(end) = rest.find('"') {
-                    let candidate = &rest[..end];
-                    if candidate.starts_with("media/") {
-                        refs.push(candidate.to_string());
-                    }
+            if let Some(rest) = trimmed.strip_prefix("photo: \"")
+                && let Some(end) = rest.find('"')
+            {
+                let candidate = &rest[..end];
+                if candidate.starts_with("media/") {
+                    refs.push(candidate.to_string());
                 }
             }
         }
@@ -1040,4 +2015,83 @@ mod tests {
 
         refs
     }
+
+    #[test]
+    fn generated_posts_do_not_inline_large_images() {
+        // A sidecar as Tinymist writes it, with a 6 KiB photo and a tiny icon.
+        let fixture = "src/pages/fixtures/sidecar_with_photo.svg";
+        let raw = std::fs::read_to_string(fixture).unwrap();
+        assert!(largest_inline_image(&raw) > INLINE_IMAGE_LIMIT);
+
+        let images_dir = std::env::temp_dir().join("lince-website-post-images-test");
+        let _ = std::fs::remove_dir_all(&images_dir);
+        let (resources, doc_svg) =
+            read_tinymist_sidecar(fixture, &images_dir, BLOG_ASSETS_PUBLIC_DIR).unwrap();
+        let html = tinymist_native_html(&resources, &doc_svg, "post");
+
+        let largest = largest_inline_image(&html);
+        assert!(
+            largest <= INLINE_IMAGE_LIMIT,
+            "{fixture} still inlines a {largest}-byte data:image payload"
+        );
+        assert_eq!(html.matches("data:image/png;base64,").count(), 1);
+        let written: Vec<_> = std::fs::read_dir(&images_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        assert_eq!(written.len(), 1);
+        assert!(written[0].starts_with("image-") && written[0].ends_with(".png"));
+        assert!(html.contains(&format!(
+            r#"xlink:href="{BLOG_ASSETS_PUBLIC_DIR}/{}" loading="lazy" decoding="async""#,
+            written[0]
+        )));
+    }
+
+    #[test]
+    fn svg_posts_expose_the_semantic_html_to_assistive_technology() {
+        let t = &get_translations()["en"];
+        let site = SiteConfig::load().unwrap();
+        let nav = || PostNav {
+            older_href: None,
+            newer_href: None,
+            series: None,
+            slides_href: None,
+            pdf_href: None,
+        };
+        let svg = r#"<svg class="typst-doc"></svg>"#;
+        let content = |body, text_body| PostContent {
+            body,
+            text_body,
+            roadmap_changes: None,
+        };
+        let html = render_blog_post(
+            t,
+            &site,
+            "/blog.html",
+            None,
+            None,
+            nav(),
+            content(svg, Some("<h1>Hello</h1>")),
+        )
+        .into_string();
+
+        assert!(html.contains(r#"<div class="blog-post-faithful" aria-hidden="true"><svg"#));
+        assert!(html.contains(r#"<div class="blog-post-text"><h1>Hello</h1></div>"#));
+        assert!(html.contains("setPostView('text')"));
+
+        let plain = render_blog_post(
+            t,
+            &site,
+            "/blog.html",
+            None,
+            None,
+            nav(),
+            content("<p>Hi</p>", None),
+        )
+        .into_string();
+        assert!(
+            !plain.contains("blog-view-toggle"),
+            "HTML-only posts need no toggle"
+        );
+    }
 }
@@ -0,0 +1,157 @@
+//! RSS feed of the blog, one per language, with the PDF edition of each post
+//! as its enclosure.
+
+use crate::{
+    compiler::days_from_civil,
+    config::SiteConfig,
+    html::OutputPage,
+    i18n::Translations,
+    pages::blog::{BlogPost, get_blog_posts, post_pdf},
+    routes::{Route, lang_suffix},
+};
+use maud::html;
+
+const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Site path of the feed in `lang_code`.
+pub fn feed_path(lang_code: &str) -> String {
+    format!("/blog/feed{}.xml", lang_suffix(lang_code))
+}
+
+/// The blog feed in the language of `t`.
+pub fn feed_pages(t: &Translations, site: &SiteConfig) -> Vec<OutputPage> {
+    let path = feed_path(t.lang_code);
+    let items: String = get_blog_posts()
+        .iter()
+        .map(|post| feed_item(t, site, post))
+        .collect();
+    let xml = format!(
+        concat!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+            "\n",
+            r#"<rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom">"#,
+            "<channel><title>{title} | Lince</title><link>{link}</link>",
+            "<description>{description}</description><language>{language}</language>",
+            r#"<atom:link href="{url}" rel="self" type="application/rss+xml"/>"#,
+            "{items}</channel></rss>\n",
+        ),
+        title = escape(t.blog_title),
+        link = escape(&Route::Blog.url(site, t.lang_code)),
+        description = escape(t.hero_tagline),
+        language = t.lang_code,
+        url = escape(&site.url(&path)),
+        items = items,
+    );
+    vec![(path.trim_start_matches('/').to_string(), xml)]
+}
+
+fn feed_item(t: &Translations, site: &SiteConfig, post: &BlogPost) -> String {
+    let link = escape(&Route::BlogPost(&post.slug).url(site, t.lang_code));
+    let pub_date = rfc822_date(&post.date)
+        .map(|date| format!("<pubDate>{date}</pubDate>"))
+        .unwrap_or_default();
+    let enclosure = post_pdf(&post.slug, t.lang_code)
+        .map(|(path, length)| {
+            format!(
+                r#"<enclosure url="{}" length="{length}" type="application/pdf"/>"#,
+                escape(&site.url(&path))
+            )
+        })
+        .unwrap_or_default();
+    format!(
+        "<item><title>{}</title><link>{link}</link><guid>{link}</guid>{pub_date}{enclosure}</item>",
+        escape(&post.localized_title(t))
+    )
+}
+
+/// `value` escaped for XML text and attribute values.
+fn escape(value: &str) -> String {
+    html! { (value) }.into_string()
+}
+
+/// An ISO `YYYY-MM-DD` date as RSS wants it: `Sun, 19 Oct 2026 00:00:00 GMT`.
+fn rfc822_date(date: &str) -> Option<String> {
+    let mut parts = date.splitn(3, '-').map(str::parse::<u32>);
+    let (year, month, day) = (
+        parts.next()?.ok()?,
+        parts.next()?.ok()?,
+        parts.next()?.ok()?,
+    );
+    let month_name = MONTHS.get(usize::try_from(month).ok()?.checked_sub(1)?)?;
+    let days = days_from_civil(year as i32, month as u8, day as u8);
+    // 1970-01-01 was a Thursday.
+    let weekday = WEEKDAYS[(days + 4).rem_euclid(7) as usize];
+    Some(format!(
+        "{weekday}, {day:02} {month_name} {year} 00:00:00 GMT"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{feed_item, feed_pages, rfc822_date};
+    use crate::{
+        config::SiteConfig,
+        i18n::get_translations,
+        pages::blog::{BlogPost, PostKind, get_blog_posts},
+    };
+    use std::{fs, path::Path};
+
+    #[test]
+    fn dates_are_written_in_rfc_822() {
+        assert_eq!(
+            rfc822_date("2026-10-19").as_deref(),
+            Some("Mon, 19 Oct 2026 00:00:00 GMT")
+        );
+        assert_eq!(
+            rfc822_date("2024-02-29").as_deref(),
+            Some("Thu, 29 Feb 2024 00:00:00 GMT")
+        );
+        assert_eq!(rfc822_date("2026-13-01"), None);
+        assert_eq!(rfc822_date(""), None);
+    }
+
+    #[test]
+    fn feeds_list_every_post_including_reviews() {
+        let site = SiteConfig::parse(&fs::read_to_string("site.toml").unwrap()).unwrap();
+        let (path, xml) = feed_pages(&get_translations()["pt-br"], &site).remove(0);
+        assert_eq!(path, "blog/feed.pt-br.xml");
+        assert!(xml.contains("<language>pt-br</language>"));
+        assert_eq!(xml.matches("<item>").count(), get_blog_posts().len());
+        assert!(xml.contains("_review.pt-br.html</link>"));
+    }
+
+    #[test]
+    fn items_enclose_the_pdf_edition_in_their_language() {
+        let site = SiteConfig::parse(&fs::read_to_string("site.toml").unwrap()).unwrap();
+        let translations = get_translations();
+        let post = BlogPost {
+            slug: "feed-test/a_b".to_string(),
+            title: "Q&A".to_string(),
+            date: "2026-10-19".to_string(),
+            series: None,
+            author: None,
+            kind: PostKind::Typst,
+        };
+        let dir = Path::new("output/blog/feed-test");
+        fs::create_dir_all(dir).unwrap();
+        fs::write(dir.join("a_b.pdf"), b"%PDF").unwrap();
+        fs::write(dir.join("a_b.zh.pdf"), b"%PDF-1.7").unwrap();
+        let en = feed_item(&translations["en"], &site, &post);
+        let zh = feed_item(&translations["zh"], &site, &post);
+        fs::remove_dir_all(dir).unwrap();
+
+        assert!(en.contains("<title>Q&amp;A</title>"));
+        assert!(en.contains("<pubDate>Mon, 19 Oct 2026 00:00:00 GMT</pubDate>"));
+        assert!(en.contains(&format!(
+            r#"<enclosure url="{}" length="4" type="application/pdf"/>"#,
+            site.url("/blog/feed-test/a_b.pdf")
+        )));
+        assert!(zh.contains(&format!(
+            r#"<enclosure url="{}" length="8" type="application/pdf"/>"#,
+            site.url("/blog/feed-test/a_b.zh.pdf")
+        )));
+    }
+}
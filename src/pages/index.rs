@@ -1,16 +1,21 @@
-use crate::i18n::{
-    LATEST_LINUX_DOWNLOAD_URL, LATEST_MACOS_DOWNLOAD_URL, LATEST_WINDOWS_DOWNLOAD_URL,
-    Translations,
-};
+use crate::config::SiteConfig;
+use crate::i18n::Translations;
+use crate::images::render_image;
 use maud::{PreEscaped, html};
 
-const INSTALL_COMMAND: &str = "curl -fsSL https://lince.social/install.sh | bash";
+/// Release assets offered by the hero download link, by the OS it detects.
+const LINUX_ASSET: &str = "lince-x86_64-unknown-linux-gnu";
+const MACOS_ASSET: &str = "lince-aarch64-apple-darwin";
+const WINDOWS_ASSET: &str = "lince-x86_64-pc-windows-msvc.exe";
+
+pub fn page_index(t: &Translations, site: &SiteConfig) -> String {
+    let install_command = site.install_command();
+    let linux_download_url = site.download_url(LINUX_ASSET);
 
-pub fn page_index(t: &Translations) -> String {
     html! {
         section.hero {
             .hero-container {
-                img.hero-logo src="assets/logo/white.svg" alt="Lince Logo";
+                img.hero-logo src=(site.href("/assets/logo/white.svg")) alt="Lince Logo";
                 h1.hero-title { (t.hero_title) }
                 p.hero-tagline { (t.hero_tagline) }
                 p.hero-subtitle { (t.hero_subtitle) }
@@ -18,16 +23,16 @@ pub fn page_index(t: &Translations) -> String {
                 .hero-button-stack {
                     .hero-buttons.hero-buttons--docs {
                         @for btn in &t.hero_doc_buttons {
-                            a class=(btn.class) href=(btn.href) { (btn.text) }
+                            a class=(btn.class) href=(btn.href.resolve(site, t.lang_code)) { (btn.text) }
                         }
                     }
 
                     .hero-install-row {
                         span.hero-install-label { (t.hero_install_label) }
                         .hero-install-command
-                            data-copy-text=(INSTALL_COMMAND)
+                            data-copy-text=(install_command)
                             onclick="copyHeroInstallFromCommand(this)" {
-                            code.hero-install-text { (INSTALL_COMMAND) }
+                            code.hero-install-text { (install_command) }
                             button.hero-install-copy
                                 type="button"
                                 title=(t.hero_install_copy)
@@ -43,10 +48,10 @@ pub fn page_index(t: &Translations) -> String {
                             " "
                             a.hero-os-download
                                 id="hero-os-download"
-                                href=(LATEST_LINUX_DOWNLOAD_URL)
-                                data-linux-href=(LATEST_LINUX_DOWNLOAD_URL)
-                                data-macos-href=(LATEST_MACOS_DOWNLOAD_URL)
-                                data-windows-href=(LATEST_WINDOWS_DOWNLOAD_URL)
+                                href=(linux_download_url)
+                                data-linux-href=(linux_download_url)
+                                data-macos-href=(site.download_url(MACOS_ASSET))
+                                data-windows-href=(site.download_url(WINDOWS_ASSET))
                                 data-linux-text=(t.hero_linux_executable)
                                 data-macos-text=(t.hero_macos_executable)
                                 data-windows-text=(t.hero_windows_executable) {
@@ -64,16 +69,16 @@ pub fn page_index(t: &Translations) -> String {
                     .content-block.content-block--with-image {
                         .content-block__text {
                             h2.content-block__title { (block.title) }
-                            p.content-block__body { (PreEscaped(block.text)) }
+                            p.content-block__body { (PreEscaped(block.body(site))) }
                         }
                         .content-block__image {
-                            img class=(img.class) src=(img.src) alt=(img.alt);
+                            (render_image(site, img.src, img.alt, img.class))
                         }
                     }
                 } @else {
                     .content-block {
                         h2.content-block__title { (block.title) }
-                        p.content-block__body { (PreEscaped(block.text)) }
+                        p.content-block__body { (PreEscaped(block.body(site))) }
                     }
                 }
             }
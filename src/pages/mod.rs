@@ -1,3 +1,9 @@
+pub mod authors;
 pub mod blog;
+pub mod feed;
 pub mod index;
+pub mod review;
+pub mod roadmap;
+pub mod slides;
+pub mod video;
 pub mod visual_identity;
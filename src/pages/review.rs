@@ -0,0 +1,233 @@
+//! Year in review: a synthetic `blog/<year>/<year>_review` post per year with
+//! at least one TMIL, built from the parsed TMIL sources and their roadmaps.
+
+use crate::{
+    config::SiteConfig,
+    i18n::Translations,
+    lint::is_template_draft,
+    pages::{
+        blog::{
+            BLOG_POSTS_ROOT, BlogPost, PostKind, collect_blog_post_files, should_skip_blog_post,
+            slug_from_path, tmil_month,
+        },
+        roadmap::render_change_list,
+    },
+    roadmap::{MonthRoadmap, find_item, roadmap_changes},
+    routes::Route,
+    tmil::{TMIL_TEMPLATE, TmilSource, read_tmil},
+};
+use maud::{Markup, html};
+use std::{collections::BTreeMap, path::Path};
+
+/// English title of a review post, after the year (`2026 in Review`).
+const REVIEW_TITLE_SUFFIX: &str = " in Review";
+/// TMIL sections aggregated month by month, by English name.
+const REVIEW_SECTIONS: [&str; 2] = ["Growth", "Programming"];
+
+pub fn review_slug(year: u32) -> String {
+    format!("{year}/{year}_review")
+}
+
+pub fn review_title(t: &Translations, year: u32) -> String {
+    t.review_title.replace("{year}", &year.to_string())
+}
+
+/// Whether `source` is a TMIL still holding template placeholders, which
+/// reviews leave out.
+fn is_draft(source: &TmilSource) -> bool {
+    read_tmil(Path::new(TMIL_TEMPLATE)).is_ok_and(|template| is_template_draft(source, &template))
+}
+
+/// One review per year that has a TMIL, dated like that year's latest TMIL.
+/// Nobody wrote it, so it has no author.
+pub fn review_posts(posts: &[BlogPost]) -> Vec<BlogPost> {
+    let mut latest: BTreeMap<u32, &BlogPost> = BTreeMap::new();
+    for post in posts {
+        let stem = post.slug.rsplit('/').next().unwrap_or_default();
+        let Some((year, _)) = tmil_month(stem) else {
+            continue;
+        };
+        let path = Path::new(BLOG_POSTS_ROOT).join(format!("{}.typ", post.slug));
+        if read_tmil(&path).is_ok_and(|source| is_draft(&source)) {
+            continue;
+        }
+        let entry = latest.entry(year).or_insert(post);
+        if post.slug > entry.slug {
+            *entry = post;
+        }
+    }
+    latest
+        .into_iter()
+        .map(|(year, post)| BlogPost {
+            slug: review_slug(year),
+            title: format!("{year}{REVIEW_TITLE_SUFFIX}"),
+            date: post.date.clone(),
+            series: None,
+            author: None,
+            kind: PostKind::Review(year),
+        })
+        .collect()
+}
+
+/// Parsed TMIL sources of `year` with their slugs, oldest month first.
+fn year_sources(year: u32) -> Vec<(String, u32, TmilSource)> {
+    let mut files = Vec::new();
+    collect_blog_post_files(Path::new(BLOG_POSTS_ROOT), &mut files);
+
+    let mut sources: Vec<(String, u32, TmilSource)> = files
+        .iter()
+        .filter_map(|file_path| {
+            let stem = file_path.file_stem()?.to_str()?;
+            if should_skip_blog_post(stem) {
+                return None;
+            }
+            let (source_year, month) = tmil_month(stem)?;
+            if source_year != year {
+                return None;
+            }
+            let source = read_tmil(file_path).ok()?;
+            if is_draft(&source) {
+                return None;
+            }
+            Some((slug_from_path(file_path)?, month, source))
+        })
+        .collect();
+    sources.sort_by_key(|(_, month, _)| *month);
+    sources
+}
+
+fn month_name(t: &Translations, month: u32) -> &'static str {
+    t.month_names[(month as usize - 1) % 12]
+}
+
+/// Body of the review of `year`: growth and programming items by month, then
+/// how the roadmap's progress and items changed over the year.
+pub fn render_review(
+    t: &Translations,
+    site: &SiteConfig,
+    year: u32,
+    roadmaps: &[MonthRoadmap],
+) -> Markup {
+    let sources = year_sources(year);
+    let post_href = |slug: &str| Route::BlogPost(slug).href(site, t.lang_code);
+
+    // Only the months listed above, so drafts stay out here too.
+    let year_roadmaps: Vec<&MonthRoadmap> = roadmaps
+        .iter()
+        .filter(|r| r.year == year && !r.items.is_empty())
+        .filter(|r| sources.iter().any(|(slug, _, _)| *slug == r.slug))
+        .collect();
+    // Progress is compared against the roadmap the year started from.
+    let baseline = roadmaps
+        .iter()
+        .rev()
+        .find(|r| r.year < year && !r.items.is_empty())
+        .or(year_roadmaps.first().copied());
+    let latest = year_roadmaps.last();
+
+    html! {
+        div.year-review {
+            h1.section-title { (review_title(t, year)) }
+            p.section-description { (t.review_intro.replace("{year}", &year.to_string())) }
+            nav.year-review__months {
+                @for (slug, month, _) in &sources {
+                    a href=(post_href(slug)) { (month_name(t, *month)) }
+                }
+            }
+            @for (slug, month, source) in &sources {
+                section.year-review__month id=(format!("month-{month:02}")) {
+                    h2 { a href=(post_href(slug)) { (month_name(t, *month)) } }
+                    @for en in REVIEW_SECTIONS {
+                        @let headings: Vec<_> = source
+                            .section_items(en)
+                            .iter()
+                            .filter_map(|item| item.heading.as_ref())
+                            .collect();
+                        @let name = source
+                            .sections
+                            .iter()
+                            .find_map(|s| s.names().filter(|names| names.en == en));
+                        @if !headings.is_empty() {
+                            h3 { (name.as_ref().map_or(en, |names| names.get(t.lang_code))) }
+                            ul.year-review__items {
+                                @for heading in headings {
+                                    @let heading = heading.get(t.lang_code);
+                                    li {
+                                        strong { (heading.title) }
+                                        @if !heading.subtitle.is_empty() {
+                                            " — " (heading.subtitle)
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            @if let (Some(baseline), Some(latest)) = (baseline, latest) {
+                section.year-review__roadmap {
+                    h2 { (t.review_roadmap_title) }
+                    ul.year-review__progress {
+                        @for item in &latest.items {
+                            @let before = find_item(&baseline.items, &[item.key()]);
+                            li {
+                                (item.text) ": "
+                                @if let Some(before) = before {
+                                    (before.progress()) "% → "
+                                }
+                                strong { (item.progress()) "%" }
+                            }
+                        }
+                    }
+                    @for roadmap in &year_roadmaps {
+                        @if let Some(changes) = roadmap_changes(roadmaps, &roadmap.slug) {
+                            h3 {
+                                a href=(post_href(&roadmap.slug)) { (month_name(t, roadmap.month)) }
+                            }
+                            (render_change_list(t, &changes))
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render_review, review_posts};
+    use crate::{
+        config::SiteConfig,
+        i18n::get_translations,
+        pages::blog::{PostKind, get_blog_posts},
+        roadmap::tmil_roadmaps,
+    };
+
+    #[test]
+    fn each_tmil_year_gets_a_review_with_its_months() {
+        let reviews = review_posts(&get_blog_posts());
+        let slugs: Vec<&str> = reviews.iter().map(|p| p.slug.as_str()).collect();
+        assert_eq!(slugs, ["2025/2025_review", "2026/2026_review"]);
+        assert_eq!(reviews[1].kind, PostKind::Review(2026));
+        assert!(reviews.iter().all(|post| post.author.is_none()));
+
+        let site = SiteConfig::load().unwrap();
+        let html = render_review(&get_translations()["pt-br"], &site, 2026, &tmil_roadmaps()).0;
+        assert!(html.contains("<h1 class=\"section-title\">Retrospectiva de 2026</h1>"));
+        assert!(
+            html.contains(r#"<h2><a href="/blog/2026/2026_01_tmil.pt-br.html">janeiro</a></h2>"#)
+        );
+        assert!(html.contains("<h3>Crescimento</h3>"));
+        assert!(html.contains("<strong>Instituto Lince no Brasil</strong>"));
+        assert!(
+            !html.contains("2025_12_tmil"),
+            "Other years are not aggregated"
+        );
+        assert!(
+            !html.contains("2026_04_tmil") && !html.contains("Outro item"),
+            "Template drafts are left out"
+        );
+        assert!(!html.contains("<ul class=\"year-review__items\"></ul>"));
+        assert!(html.contains("class=\"year-review__roadmap\""));
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{
+    config::SiteConfig,
+    i18n::Translations,
+    roadmap::{ItemState, MonthRoadmap, RoadmapChange, RoadmapItem, item_history, tmil_roadmaps},
+    routes::Route,
+};
+use maud::{Markup, html};
+
+pub fn state_label(
+    t: &Translations,
+    state: ItemState,
+) -> (&'static str, &'static str, &'static str) {
+    match state {
+        ItemState::Todo => ("todo", "☐", t.roadmap_todo),
+        ItemState::InProgress => ("in-progress", "◐", t.roadmap_in_progress),
+        ItemState::Done => ("done", "☑", t.roadmap_done),
+    }
+}
+
+/// The checklist of the latest TMIL that has one, with per-subtree progress
+/// and links to the months where each item changed.
+pub fn page_roadmap(t: &Translations, site: &SiteConfig) -> String {
+    let roadmaps = tmil_roadmaps();
+    let latest = roadmaps.iter().rev().find(|r| !r.items.is_empty());
+
+    html! {
+        main.main-content {
+            section.blog-header {
+                h1.section-title { (t.roadmap_title) }
+                @if let Some(latest) = latest {
+                    p.section-description {
+                        (t.roadmap_intro) " "
+                        a href=(Route::BlogPost(&latest.slug).href(site, t.lang_code)) { (latest.label()) }
+                    }
+                }
+            }
+            @if let Some(latest) = latest {
+                section.roadmap {
+                    (render_items(t, site, &roadmaps, &latest.items, &[]))
+                }
+            }
+        }
+    }
+    .0
+}
+
+fn render_items(
+    t: &Translations,
+    site: &SiteConfig,
+    roadmaps: &[MonthRoadmap],
+    items: &[RoadmapItem],
+    parent: &[String],
+) -> Markup {
+    html! {
+        ul.roadmap-list {
+            @for item in items {
+                @let path = [parent, &[item.key()]].concat();
+                @let (state, symbol, label) = state_label(t, item.state);
+                @let history = item_history(roadmaps, &path);
+                li.roadmap-item data-state=(state) {
+                    div.roadmap-item__row {
+                        span.roadmap-item__state title=(label) aria-label=(label) { (symbol) }
+                        span.roadmap-item__text { (item.text) }
+                        @if !item.children.is_empty() {
+                            span.roadmap-item__progress {
+                                progress max="100" value=(item.progress()) {}
+                                " " (item.progress()) "%"
+                            }
+                        }
+                    }
+                    @if !history.is_empty() {
+                        p.roadmap-item__history {
+                            (t.roadmap_history) " "
+                            @for (index, month) in history.iter().enumerate() {
+                                @if index > 0 { ", " }
+                                a href=(Route::BlogPost(&month.slug).href(site, t.lang_code)) { (month.label()) }
+                            }
+                        }
+                    }
+                    @if !item.children.is_empty() {
+                        (render_items(t, site, roadmaps, &item.children, &path))
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// "Roadmap changes this month" summary shown on a TMIL post.
+pub fn render_roadmap_changes(
+    t: &Translations,
+    site: &SiteConfig,
+    changes: &[RoadmapChange],
+) -> Markup {
+    html! {
+        section.roadmap-changes {
+            h2.roadmap-changes__title { (t.roadmap_changes_title) }
+            (render_change_list(t, changes))
+            a.roadmap-changes__link href=(Route::Roadmap.href(site, t.lang_code)) { (t.roadmap_title) }
+        }
+    }
+}
+
+/// Added, removed and moved items, or a note that nothing changed.
+pub fn render_change_list(t: &Translations, changes: &[RoadmapChange]) -> Markup {
+    let path = |context: &[String], text: &str| {
+        context
+            .iter()
+            .map(String::as_str)
+            .chain([text])
+            .collect::<Vec<_>>()
+            .join(" › ")
+    };
+
+    html! {
+        @if changes.is_empty() {
+            p { (t.roadmap_unchanged) }
+        } @else {
+            ul.roadmap-changes__list {
+                @for change in changes {
+                    @match change {
+                        RoadmapChange::Added { context, text, state } => {
+                            li data-change="added" {
+                                strong { (t.roadmap_added) } " " (path(context, text))
+                                " (" (state_label(t, *state).2) ")"
+                            }
+                        }
+                        RoadmapChange::Removed { context, text } => {
+                            li data-change="removed" {
+                                strong { (t.roadmap_removed) } " " (path(context, text))
+                            }
+                        }
+                        RoadmapChange::Moved { context, text, from, to } => {
+                            li data-change="moved" {
+                                (path(context, text)) ": "
+                                (state_label(t, *from).2) " → "
+                                strong { (state_label(t, *to).2) }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::page_roadmap;
+    use crate::{config::SiteConfig, i18n::get_translations};
+
+    #[test]
+    fn roadmap_page_shows_the_latest_checklist_with_progress() {
+        let html = page_roadmap(&get_translations()["pt-br"], &SiteConfig::load().unwrap());
+        assert!(html.contains(r#"<a href="/blog/2026/2026_04_tmil.pt-br.html">2026-04</a>"#));
+        assert!(html.contains("Dogfooding (Web HTML)"));
+        assert!(html.contains(r#"<li class="roadmap-item" data-state="done">"#));
+        // Connection: CRUD done, Public/private to do, Transaction in progress.
+        assert!(html.contains(r#"<progress max="100" value="50"></progress> 50%"#));
+        assert!(html.contains(r#"<a href="/blog/2026/2026_03_tmil.pt-br.html">2026-03</a>"#));
+    }
+}
@@ -0,0 +1,426 @@
+//! TMIL slide decks: every `*_tmil.typ` compiled with `--input tmil=true`
+//! into the `Route::SlideDeck` page of each language, wrapped in a fullscreen
+//! viewer.
+
+use crate::{
+    cache::typst_fingerprint,
+    compiler::{TypstBackend, default_backend},
+    config::SiteConfig,
+    html::{OutputPage, route_pages},
+    i18n::Translations,
+    pages::blog::{
+        BLOG_ASSETS_DIR, BLOG_ASSETS_PUBLIC_DIR, BLOG_POSTS_ROOT, BlogSidecars,
+        TYPST_SHARED_SOURCES, collect_blog_post_files, extract_parenthesized_block,
+        should_skip_blog_post, slug_from_path, tmil_month,
+    },
+    routes::Route,
+    sidecar::{attr, externalize_images, remove_attr, set_attr, split_glyphs, start_tag_end},
+};
+use maud::{DOCTYPE, Markup, PreEscaped, html};
+use std::{
+    collections::{BTreeMap, HashMap, hash_map::DefaultHasher},
+    fs,
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+};
+
+/// `<meta>` holding the fingerprint a deck was built from, so unchanged decks are skipped.
+const FINGERPRINT_META: &str = "deck-fingerprint";
+/// Title line `tmil_slides` renders by default, in `tmil_langs` order.
+const TMIL_DECK_TITLE: &str = "Este Mês na Lince | 本月在 Lince | This Month in Lince";
+
+/// `Route::SlideDeck` parameter of the TMIL post `stem` (`2026/04`).
+fn deck_month(stem: &str) -> Option<String> {
+    let (year, month) = tmil_month(stem)?;
+    Some(format!("{year}/{month:02}"))
+}
+
+/// Href of the deck of the TMIL post `stem` in `lang_code`, if `decks` has one.
+pub fn deck_href(
+    site: &SiteConfig,
+    decks: &[OutputPage],
+    stem: &str,
+    lang_code: &str,
+) -> Option<String> {
+    let month = deck_month(stem)?;
+    let route = Route::SlideDeck(&month);
+    let path = route.path(site, lang_code);
+    decks
+        .iter()
+        .any(|(deck, _)| *deck == path)
+        .then(|| route.href(site, lang_code))
+}
+
+/// Fingerprint of the deck page itself, recorded in its `FINGERPRINT_META`.
+fn deck_fingerprint(site: &SiteConfig, t: &Translations, sources: u64) -> String {
+    // Decks link into the site, so moving it to another base path or URL
+    // scheme rebuilds them.
+    let mut hasher = DefaultHasher::new();
+    sources.hash(&mut hasher);
+    site.base_path.hash(&mut hasher);
+    site.url_scheme.hash(&mut hasher);
+    t.lang_code.hash(&mut hasher);
+    t.slides_fullscreen.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// The deck published at `path` under `output/` by an earlier build.
+fn published_deck(path: &str) -> Option<String> {
+    fs::read_to_string(Path::new("output").join(path)).ok()
+}
+
+/// Fingerprint a deck page was built from.
+fn built_from(html: &str) -> Option<&str> {
+    let marker = format!("<meta name=\"{FINGERPRINT_META}\" content=\"");
+    let start = html.find(&marker)? + marker.len();
+    html[start..].split('"').next()
+}
+
+/// Language line (`pt-BR | zh-CN | en`) and item count of each `tmil_section`
+/// in `#let sections`, in order.
+fn tmil_sections(source: &str) -> Vec<(String, usize)> {
+    let Some(sections) = extract_parenthesized_block(source, "#let sections = (") else {
+        return Vec::new();
+    };
+    let mut out = Vec::new();
+    let mut rest = sections.as_str();
+    while let Some(args) = extract_parenthesized_block(rest, "tmil_section(") {
+        let name = args
+            .split('"')
+            .nth(1)
+            .map(|name| {
+                name.split('|')
+                    .map(str::trim)
+                    .collect::<Vec<_>>()
+                    .join(" | ")
+            })
+            .unwrap_or_default();
+        let items = args
+            .split_once("items:")
+            .map(|(_, ident)| ident.trim().trim_end_matches(',').trim())
+            .and_then(|ident| extract_parenthesized_block(source, &format!("#let {ident} = (")))
+            .map(|block| block.matches("tmil_item(").count())
+            .unwrap_or(0);
+        out.push((name, items));
+        let consumed = rest.find("tmil_section(").unwrap_or(0) + "tmil_section(".len() + args.len();
+        rest = &rest[consumed..];
+    }
+    out
+}
+
+/// Language line shown above each of `page_count` slides: the section title
+/// slide and every item slide carry their section's line, the opening and
+/// closing slides the deck title.
+fn slide_lang_lines(source: &str, page_count: usize) -> Vec<String> {
+    let mut lines = vec![TMIL_DECK_TITLE.to_string()];
+    for (name, items) in tmil_sections(source) {
+        lines.extend(std::iter::repeat_n(name, items + 1));
+    }
+    // A layout the source parsing got wrong is better labeled by the title alone.
+    if lines.len() >= page_count {
+        lines = Vec::new();
+    }
+    lines.resize(page_count, TMIL_DECK_TITLE.to_string());
+    lines
+}
+
+/// Make a page SVG scale with the viewer instead of its fixed `pt` size.
+fn fluid_slide(svg: &str) -> String {
+    let Some(start) = svg.find("<svg") else {
+        return svg.to_string();
+    };
+    let Some(end) = start_tag_end(svg, start) else {
+        return svg.to_string();
+    };
+    let mut root = svg[start..end].to_string();
+    if attr(&root, "viewBox").is_none()
+        && let (Some(width), Some(height)) = (attr(&root, "width"), attr(&root, "height"))
+    {
+        let view_box = format!(
+            "0 0 {} {}",
+            width.trim_end_matches("pt"),
+            height.trim_end_matches("pt")
+        );
+        root = set_attr(&root, "viewBox", &view_box);
+    }
+    root = remove_attr(&root, "height");
+    root = set_attr(&root, "width", "100%");
+    format!("{}{root}{}", &svg[..start], &svg[end..])
+}
+
+/// The deck pages of every TMIL post in every language. A deck is compiled
+/// once for all languages, and only when a language's published page was built
+/// from other sources; otherwise the published pages are kept. Runs before
+/// the posts are rendered so they can link to the decks.
+pub fn generate_slide_decks(
+    site: &SiteConfig,
+    translations: &HashMap<&str, Translations>,
+    sidecars: &BlogSidecars,
+) -> Vec<OutputPage> {
+    let mut decks = Vec::new();
+    let mut files = Vec::new();
+    collect_blog_post_files(Path::new(BLOG_POSTS_ROOT), &mut files);
+    files.sort();
+
+    for file_path in files {
+        let Some(stem) = file_path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        if should_skip_blog_post(stem) {
+            continue;
+        }
+        let Some(deck) = deck_month(stem) else {
+            continue;
+        };
+        let slug = slug_from_path(&file_path).unwrap_or_else(|| stem.to_string());
+        let deps: Vec<PathBuf> = match sidecars.deps(&slug.replace('/', "__")) {
+            Some(deps) => deps.to_vec(),
+            None => TYPST_SHARED_SOURCES.iter().map(PathBuf::from).collect(),
+        };
+        let Some(sources) = typst_fingerprint(&file_path, &deps) else {
+            continue;
+        };
+        let route = Route::SlideDeck(&deck);
+        let published: Vec<(&Translations, String, Option<String>)> = translations
+            .values()
+            .map(|t| {
+                let fingerprint = deck_fingerprint(site, t, sources);
+                (
+                    t,
+                    fingerprint,
+                    published_deck(&route.path(site, t.lang_code)),
+                )
+            })
+            .collect();
+        let up_to_date = published.iter().all(|(_, fingerprint, html)| {
+            html.as_deref().and_then(built_from) == Some(fingerprint.as_str())
+        });
+
+        let pages = match up_to_date {
+            true => Ok(Vec::new()),
+            false => default_backend().compile_pages(&file_path, &[("tmil", "true")]),
+        };
+        let pages = match pages {
+            Ok(pages) => pages,
+            Err(err) => {
+                // Keep the last published deck rather than dropping the link.
+                eprintln!("No slide deck for {slug}:\n{err}");
+                Vec::new()
+            }
+        };
+        if pages.is_empty() {
+            for (t, _, html) in published {
+                if let Some(html) = html {
+                    decks.extend(route_pages(site, route, t.lang_code, html));
+                }
+            }
+            continue;
+        }
+
+        let source = fs::read_to_string(&file_path).unwrap_or_default();
+        let lang_lines = slide_lang_lines(&source, pages.len());
+        let month_label = deck.replace('/', "-");
+        for (t, fingerprint, _) in published {
+            let html = render_deck(
+                site,
+                t,
+                &pages,
+                &lang_lines,
+                &month_label,
+                &Route::BlogPost(&slug).href(site, t.lang_code),
+                &fingerprint,
+            );
+            decks.extend(route_pages(site, route, t.lang_code, html));
+        }
+        println!("Slide deck {}: {} slides", route.key(), pages.len());
+    }
+    decks
+}
+
+/// Wrap page SVGs in the viewer. Glyphs are shared by all slides through one
+/// hidden `<defs>`, and photos are moved next to the blog's.
+fn render_deck(
+    site: &SiteConfig,
+    t: &Translations,
+    pages: &[String],
+    lang_lines: &[String],
+    month_label: &str,
+    post_href: &str,
+    fingerprint: &str,
+) -> String {
+    let assets_href = site.href(BLOG_ASSETS_PUBLIC_DIR);
+    let mut glyphs = BTreeMap::new();
+    let slides: Vec<String> = pages
+        .iter()
+        .map(|page| {
+            let page = externalize_images(page, Path::new(BLOG_ASSETS_DIR), &assets_href)
+                .expect("Failed to extract slide images");
+            let (page, page_glyphs) = split_glyphs(&page);
+            glyphs.extend(page_glyphs);
+            fluid_slide(&page)
+        })
+        .collect();
+    let defs: String = glyphs.into_values().collect();
+    let total = slides.len();
+
+    let markup: Markup = html! {
+        (DOCTYPE)
+        html lang=(t.lang_code) data-theme="dark" {
+            head {
+                meta charset="UTF-8";
+                meta name="viewport" content="width=device-width, initial-scale=1.0";
+                meta name=(FINGERPRINT_META) content=(fingerprint);
+                link rel="icon" href=(site.href("/assets/black_in_white.ico")) type="image/x-icon";
+                link rel="stylesheet" href=(site.href("/assets/style.css"));
+                title { (TMIL_DECK_TITLE) " | " (month_label) }
+            }
+            body.slides-viewer {
+                header.slides-viewer__bar {
+                    a.slides-viewer__back href=(post_href) { "← " (month_label) }
+                    p.slides-viewer__lang-line aria-live="polite" {
+                        (lang_lines.first().map(String::as_str).unwrap_or(TMIL_DECK_TITLE))
+                    }
+                    span.slides-viewer__counter { "1 / " (total) }
+                    button.slides-viewer__fullscreen type="button" aria-label=(t.slides_fullscreen) { "⛶" }
+                }
+                div.slides-viewer__progress aria-hidden="true" {
+                    div.slides-viewer__progress-bar {}
+                }
+                svg.slides-viewer__glyphs xmlns="http://www.w3.org/2000/svg"
+                    xmlns:xlink="http://www.w3.org/1999/xlink" aria-hidden="true" {
+                    defs { (PreEscaped(defs)) }
+                }
+                main.slides-viewer__deck {
+                    @for (index, slide) in slides.iter().enumerate() {
+                        section.slides-viewer__slide id=(format!("slide-{}", index + 1))
+                            data-lang-line=(lang_lines[index])
+                            aria-label=(format!("{} / {total}", index + 1)) {
+                            (PreEscaped(slide))
+                        }
+                    }
+                }
+                script { (PreEscaped(VIEWER_SCRIPT)) }
+            }
+        }
+    };
+    markup.into_string()
+}
+
+/// Without JavaScript the slides simply stack; with it one slide fills the
+/// viewport and `#<n>` deep-links to a slide.
+const VIEWER_SCRIPT: &str = r#"(function(){
+  const slides = Array.from(document.querySelectorAll('.slides-viewer__slide'));
+  if (!slides.length) return;
+  document.body.classList.add('slides-viewer--js');
+  const counter = document.querySelector('.slides-viewer__counter');
+  const bar = document.querySelector('.slides-viewer__progress-bar');
+  const line = document.querySelector('.slides-viewer__lang-line');
+  let current = 0;
+
+  function show(index) {
+    current = Math.max(0, Math.min(slides.length - 1, index));
+    slides.forEach((slide, i) => slide.classList.toggle('is-current', i === current));
+    counter.textContent = (current + 1) + ' / ' + slides.length;
+    bar.style.width = ((current + 1) / slides.length * 100) + '%';
+    line.textContent = slides[current].dataset.langLine;
+    history.replaceState(null, '', '#' + (current + 1));
+  }
+
+  function fromHash() {
+    const n = parseInt(location.hash.slice(1), 10);
+    return Number.isFinite(n) ? n - 1 : 0;
+  }
+
+  function toggleFullscreen() {
+    if (document.fullscreenElement) document.exitFullscreen();
+    else document.documentElement.requestFullscreen().catch(() => {});
+  }
+
+  document.addEventListener('keydown', (e) => {
+    if (e.altKey || e.ctrlKey || e.metaKey) return;
+    switch (e.key) {
+      case 'ArrowRight': case 'ArrowDown': case 'PageDown': show(current + 1); break;
+      case 'ArrowLeft': case 'ArrowUp': case 'PageUp': show(current - 1); break;
+      case ' ': show(current + (e.shiftKey ? -1 : 1)); break;
+      case 'Home': show(0); break;
+      case 'End': show(slides.length - 1); break;
+      case 'f': case 'F': toggleFullscreen(); break;
+      default: return;
+    }
+    e.preventDefault();
+  });
+  document.querySelector('.slides-viewer__deck').addEventListener('click', () => show(current + 1));
+  document.querySelector('.slides-viewer__fullscreen').addEventListener('click', toggleFullscreen);
+  window.addEventListener('hashchange', () => show(fromHash()));
+  show(fromHash());
+})();"#;
+
+#[cfg(test)]
+mod tests {
+    use super::{TMIL_DECK_TITLE, deck_fingerprint, fluid_slide, render_deck, slide_lang_lines};
+    use crate::{
+        config::{SiteConfig, UrlScheme},
+        i18n::get_translations,
+    };
+
+    #[test]
+    fn tmil_slides_are_labeled_with_their_section_language_line() {
+        let source = std::fs::read_to_string("content/blog/posts/2026/2026_04_tmil.typ").unwrap();
+        // Title, then per section its title slide and one slide per item, then closing.
+        let lines = slide_lang_lines(&source, 8);
+        assert_eq!(lines[0], TMIL_DECK_TITLE);
+        assert_eq!(lines[1], "Crescimento | 成长工作 | Growth");
+        assert_eq!(lines[2], "Crescimento | 成长工作 | Growth");
+        assert_eq!(lines[3], "Programação | 开发 | Programming");
+        assert_eq!(lines[5], "Roteiro | 路线图 | Roadmap");
+        assert_eq!(lines[6], "Roteiro | 路线图 | Roadmap");
+        assert_eq!(lines[7], TMIL_DECK_TITLE);
+
+        // Fewer pages than parsed sections: the parse is wrong, use the title.
+        assert!(
+            slide_lang_lines(&source, 3)
+                .iter()
+                .all(|l| l == TMIL_DECK_TITLE)
+        );
+    }
+
+    #[test]
+    fn decks_share_glyphs_and_scale_with_the_viewer() {
+        let page = |text: &str| {
+            format!(
+                r##"<svg class="typst-doc" viewBox="0 0 800 450" width="800pt" height="450pt"><defs id="glyph"><symbol id="gA" overflow="visible"><path d="M0 0"/></symbol></defs><use xlink:href="#gA"/>{text}</svg>"##
+            )
+        };
+        let html = render_deck(
+            &SiteConfig::load().unwrap(),
+            &get_translations()["pt-br"],
+            &[page("one"), page("two")],
+            &["a".to_string(), "b".to_string()],
+            "2026-04",
+            "/blog/2026/2026_04_tmil.pt-br.html",
+            "00ff",
+        );
+        assert_eq!(html.matches(r#"<symbol id="gA""#).count(), 1);
+        assert_eq!(html.matches(r##"xlink:href="#gA""##).count(), 2);
+        assert!(
+            html.contains(
+                r#"<section class="slides-viewer__slide" id="slide-2" data-lang-line="b""#
+            )
+        );
+        assert!(html.contains(r#"<meta name="deck-fingerprint" content="00ff">"#));
+        assert!(html.contains(r#"<html lang="pt-br""#));
+        assert!(html.contains(r#"aria-label="Tela cheia""#));
+        assert!(!html.contains(r#"height="450pt""#));
+
+        let mut site = SiteConfig::load().unwrap();
+        let t = &get_translations()["en"];
+        let suffix = deck_fingerprint(&site, t, 7);
+        site.url_scheme = UrlScheme::Directories;
+        assert_ne!(deck_fingerprint(&site, t, 7), suffix);
+
+        assert_eq!(
+            fluid_slide(r#"<svg width="10pt" height="5pt"></svg>"#),
+            r#"<svg viewBox="0 0 10 5" width="100%"></svg>"#
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use crate::config::{SiteConfig, SiteLink};
+use crate::i18n::Translations;
+use maud::{Markup, html};
+use std::{fs, path::Path};
+
+/// Offline cache of video thumbnails (`{id}.jpg`) and optional titles (`{id}.txt`).
+/// Filled by `mise video-thumbnails`, read by every build.
+const VIDEO_CACHE_DIR: &str = "content/blog/media/video";
+const VIDEO_OUTPUT_DIR: &str = "output/assets/blog/video";
+const VIDEO_PUBLIC_DIR: &str = "/assets/blog/video";
+
+/// How a post's `video_url` is rendered.
+pub enum PostVideo {
+    /// Click-to-load facade that only contacts YouTube after the reader clicks.
+    YouTube {
+        id: String,
+        url: String,
+        title: String,
+        thumbnail: Option<String>,
+    },
+    /// Unknown provider: keep the plain breadcrumb link.
+    Link(String),
+}
+
+impl PostVideo {
+    pub fn resolve(site: &SiteConfig, url: &str, fallback_title: &str) -> Self {
+        let Some(id) = youtube_video_id(url) else {
+            return PostVideo::Link(url.to_string());
+        };
+        let title = fs::read_to_string(format!("{VIDEO_CACHE_DIR}/{id}.txt"))
+            .ok()
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+            .unwrap_or_else(|| fallback_title.to_string());
+        let thumbnail = Path::new(VIDEO_CACHE_DIR)
+            .join(format!("{id}.jpg"))
+            .is_file()
+            .then(|| site.href(&format!("{VIDEO_PUBLIC_DIR}/{id}.jpg")));
+        PostVideo::YouTube {
+            id,
+            url: url.to_string(),
+            title,
+            thumbnail,
+        }
+    }
+
+    /// The video's own page, linked from the post's breadcrumbs.
+    pub fn url(&self) -> &str {
+        match self {
+            PostVideo::YouTube { url, .. } | PostVideo::Link(url) => url,
+        }
+    }
+}
+
+fn is_valid_video_id(id: &str) -> bool {
+    id.len() == 11
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Extract the video id from the YouTube URL shapes we publish
+/// (`youtu.be/ID`, `watch?v=ID`, `/embed/ID`, `/shorts/ID`, `/live/ID`).
+/// Channel URLs (`youtube.com/@handle`) are not videos and yield `None`.
+pub fn youtube_video_id(url: &str) -> Option<String> {
+    let rest = url
+        .trim()
+        .strip_prefix("https://")
+        .or_else(|| url.trim().strip_prefix("http://"))?;
+    let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+    let host = host.strip_prefix("www.").unwrap_or(host);
+    let host = host.strip_prefix("m.").unwrap_or(host);
+    let (path, query) = path.split_once('?').unwrap_or((path, ""));
+
+    let id = match host {
+        "youtu.be" => path.split('/').next()?.to_string(),
+        "youtube.com" | "youtube-nocookie.com" => {
+            if path == "watch" {
+                query
+                    .split('&')
+                    .find_map(|pair| pair.strip_prefix("v="))?
+                    .to_string()
+            } else {
+                let (kind, id) = path.split_once('/')?;
+                if !matches!(kind, "embed" | "shorts" | "live") {
+                    return None;
+                }
+                id.split('/').next()?.to_string()
+            }
+        }
+        _ => return None,
+    };
+
+    is_valid_video_id(&id).then_some(id)
+}
+
+/// Copy every cached thumbnail into the output tree, where the facades of
+/// `PostVideo::resolve` link them. Run by the build after writing the pages.
+pub fn publish_thumbnails() -> Result<(), String> {
+    let Ok(entries) = fs::read_dir(VIDEO_CACHE_DIR) else {
+        return Ok(());
+    };
+    fs::create_dir_all(VIDEO_OUTPUT_DIR)
+        .map_err(|e| format!("Failed to create {VIDEO_OUTPUT_DIR}: {e}"))?;
+    for entry in entries.flatten() {
+        let source = entry.path();
+        if source.extension().is_some_and(|ext| ext == "jpg") {
+            let target = Path::new(VIDEO_OUTPUT_DIR).join(entry.file_name());
+            fs::copy(&source, &target)
+                .map_err(|e| format!("Failed to publish {}: {e}", source.display()))?;
+        }
+    }
+    Ok(())
+}
+
+/// Facade shown in place of the player. Without JavaScript it is a plain link
+/// to YouTube; with JavaScript, `loadVideoEmbed` swaps in a youtube-nocookie
+/// iframe once the reader clicks.
+pub fn render_video_facade(t: &Translations, site: &SiteConfig, video: &PostVideo) -> Markup {
+    let PostVideo::YouTube {
+        id,
+        url,
+        title,
+        thumbnail,
+    } = video
+    else {
+        return html! {};
+    };
+
+    html! {
+        figure.video-embed {
+            a.video-facade
+                href=(url)
+                data-video-id=(id)
+                data-video-title=(title)
+                onclick="return loadVideoEmbed(this)" {
+                @if let Some(src) = thumbnail {
+                    img.video-facade__thumbnail src=(src) alt=(title) loading="lazy" width="480" height="360";
+                }
+                span.video-facade__title { (title) }
+                span.video-facade__play aria-hidden="true" { "▶" }
+                span.visually-hidden { (t.blog_video_play) }
+            }
+            figcaption.video-embed__consent {
+                (t.blog_video_consent) " "
+                a href=(site.link(SiteLink::YouTube)) target="_blank" rel="noopener noreferrer" { (t.nav_youtube) }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PostVideo, render_video_facade, youtube_video_id};
+    use crate::{config::SiteConfig, i18n::get_translations};
+
+    #[test]
+    fn youtube_urls_are_recognized() {
+        for url in [
+            "https://youtu.be/iH4L37GrPBE?si=yeZowC8tK3VeoS-L",
+            "https://www.youtube.com/watch?v=iH4L37GrPBE&t=42",
+            "https://m.youtube.com/watch?feature=share&v=iH4L37GrPBE",
+            "https://www.youtube.com/embed/iH4L37GrPBE",
+            "https://www.youtube-nocookie.com/embed/iH4L37GrPBE",
+            "https://youtube.com/shorts/iH4L37GrPBE",
+        ] {
+            assert_eq!(
+                youtube_video_id(url).as_deref(),
+                Some("iH4L37GrPBE"),
+                "Failed to parse {url}"
+            );
+        }
+        for url in [
+            "https://www.youtube.com/@lince.social",
+            "https://vimeo.com/123456",
+            "https://youtu.be/short",
+            "https://www.youtube.com/playlist?list=PL123",
+        ] {
+            assert_eq!(youtube_video_id(url), None, "Unexpected id for {url}");
+        }
+    }
+
+    #[test]
+    fn facade_does_not_contact_youtube_before_consent() {
+        let t = &get_translations()["en"];
+        let site = SiteConfig::load().unwrap();
+        let video = PostVideo::resolve(&site, "https://youtu.be/iH4L37GrPBE", "TMIL");
+        let html = render_video_facade(t, &site, &video).into_string();
+        assert!(!html.contains("<iframe"), "Facade must not embed an iframe");
+        assert!(
+            !html.contains("ytimg.com"),
+            "Thumbnail must be served locally"
+        );
+        assert!(html.contains("data-video-id=\"iH4L37GrPBE\""));
+
+        let link = PostVideo::resolve(&site, "https://vimeo.com/123456", "TMIL");
+        assert!(matches!(link, PostVideo::Link(_)));
+        assert!(
+            render_video_facade(t, &site, &link)
+                .into_string()
+                .is_empty()
+        );
+    }
+}
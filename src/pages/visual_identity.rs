@@ -1,3 +1,6 @@
+use crate::config::SiteConfig;
+use crate::i18n::{IdentityText, Translations};
+use crate::images::render_image;
 use maud::html;
 
 struct AssetLink {
@@ -5,21 +8,17 @@ struct AssetLink {
     href: &'static str,
 }
 
-struct IdentitySection {
-    title: &'static str,
-    description: Option<&'static str>,
+struct IdentitySection<'a> {
+    text: &'a IdentityText,
     preview_src: &'static str,
-    preview_alt: &'static str,
     links: Vec<AssetLink>,
 }
 
-fn sections() -> Vec<IdentitySection> {
+fn sections(t: &Translations) -> Vec<IdentitySection<'_>> {
     vec![
         IdentitySection {
-            title: "White Logo",
-            description: Some("Use on dark backgrounds."),
+            text: &t.visual_identity_white,
             preview_src: "/assets/logo/white.svg",
-            preview_alt: "Lince white logo",
             links: vec![
                 AssetLink {
                     label: "SVG",
@@ -36,10 +35,8 @@ fn sections() -> Vec<IdentitySection> {
             ],
         },
         IdentitySection {
-            title: "White on Black",
-            description: Some("Logo with fixed dark background."),
+            text: &t.visual_identity_white_in_black,
             preview_src: "/assets/logo/white_in_black.svg",
-            preview_alt: "Lince white logo on black background",
             links: vec![
                 AssetLink {
                     label: "SVG",
@@ -56,10 +53,8 @@ fn sections() -> Vec<IdentitySection> {
             ],
         },
         IdentitySection {
-            title: "Black Logo",
-            description: Some("Use on light backgrounds."),
+            text: &t.visual_identity_black,
             preview_src: "/assets/logo/black.svg",
-            preview_alt: "Lince black logo",
             links: vec![
                 AssetLink {
                     label: "SVG",
@@ -76,10 +71,8 @@ fn sections() -> Vec<IdentitySection> {
             ],
         },
         IdentitySection {
-            title: "Black on White",
-            description: None,
+            text: &t.visual_identity_black_in_white,
             preview_src: "/assets/logo/black_in_white.svg",
-            preview_alt: "Lince black logo on white background",
             links: vec![
                 AssetLink {
                     label: "SVG",
@@ -102,34 +95,32 @@ fn sections() -> Vec<IdentitySection> {
     ]
 }
 
-pub fn page_visual_identity() -> String {
-    let sections = sections();
+pub fn page_visual_identity(t: &Translations, site: &SiteConfig) -> String {
+    let sections = sections(t);
 
     html! {
         main.main-content {
             section.content-block {
-                h1.content-block__title { "Visual Identity" }
-                p.content-block__body {
-                    "Official logo files for download in SVG, PNG, JPG, and ICO formats."
-                }
+                h1.content-block__title { (t.visual_identity_title) }
+                p.content-block__body { (t.visual_identity_intro) }
             }
 
             @for section_data in sections {
                 section.content-block.content-block--with-image {
                     .content-block__text {
-                        h2.content-block__title { (section_data.title) }
-                        @if let Some(desc) = section_data.description {
+                        h2.content-block__title { (section_data.text.title) }
+                        @if let Some(desc) = section_data.text.description {
                             p.content-block__body { (desc) }
                         }
                         p.content-block__body {
                             @for (i, link) in section_data.links.iter().enumerate() {
                                 @if i > 0 { " • " }
-                                a href=(link.href) { (link.label) }
+                                a href=(site.href(link.href)) { (link.label) }
                             }
                         }
                     }
                     .content-block__image {
-                        img src=(section_data.preview_src) alt=(section_data.preview_alt);
+                        (render_image(site, section_data.preview_src, section_data.text.alt, ""))
                     }
                 }
             }
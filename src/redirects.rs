@@ -0,0 +1,203 @@
+//! Redirects from paths that no longer hold a page: the moved pages listed in
+//! `redirects.toml` and short links such as `/tmil/latest`. Each becomes a
+//! meta-refresh page in `output/`, since GitHub Pages has no server-side
+//! redirects, and optionally a `_redirects` rule for hosts that have them.
+
+use crate::{
+    config::SiteConfig,
+    html::{OutputPage, redirect_page},
+    images::IMAGE_PUBLIC_DIR,
+    pages::blog::{BLOG_ASSETS_PUBLIC_DIR, get_blog_posts, tmil_month},
+    routes::Route,
+    share_card::SHARE_CARD_PUBLIC_DIR,
+};
+use serde::Deserialize;
+use std::fs;
+
+const REDIRECTS_PATH: &str = "redirects.toml";
+/// Short link to the newest TMIL, in English.
+const LATEST_TMIL_PATH: &str = "/tmil/latest";
+/// Assets named by fingerprint, which never change under the same URL.
+const FINGERPRINTED_DIRS: [&str; 3] = [
+    SHARE_CARD_PUBLIC_DIR,
+    IMAGE_PUBLIC_DIR,
+    BLOG_ASSETS_PUBLIC_DIR,
+];
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RedirectMap {
+    /// Also write `_redirects` and `_headers`.
+    #[serde(default)]
+    host_files: bool,
+    #[serde(default, rename = "redirect")]
+    redirects: Vec<Redirect>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Redirect {
+    /// Site path, without `base_path`.
+    from: String,
+    /// `Route::key` of the page it moved to.
+    to: String,
+    #[serde(default = "default_lang")]
+    lang: String,
+}
+
+fn default_lang() -> String {
+    "en".to_string()
+}
+
+/// Path under `output/` that serves `from`: the file itself, or the
+/// `index.html` of a path without an extension (`/tmil/latest`).
+fn output_path(from: &str) -> String {
+    let path = from.trim_matches('/');
+    if path
+        .rsplit('/')
+        .next()
+        .is_some_and(|name| name.contains('.'))
+    {
+        path.to_string()
+    } else if path.is_empty() {
+        "index.html".to_string()
+    } else {
+        format!("{path}/index.html")
+    }
+}
+
+/// Slug of the newest TMIL.
+fn latest_tmil() -> Option<String> {
+    get_blog_posts()
+        .into_iter()
+        .find(|post| post.slug.rsplit('/').next().and_then(tmil_month).is_some())
+        .map(|post| post.slug)
+}
+
+/// Long-lived caching for the fingerprinted assets.
+fn headers(site: &SiteConfig) -> String {
+    FINGERPRINTED_DIRS
+        .iter()
+        .map(|dir| {
+            format!(
+                "{}/*\n  Cache-Control: public, max-age=31536000, immutable\n",
+                site.href(dir)
+            )
+        })
+        .collect()
+}
+
+fn build_redirects(
+    site: &SiteConfig,
+    map: &RedirectMap,
+    latest_tmil: Option<&str>,
+    pages: &[OutputPage],
+) -> Result<Vec<OutputPage>, String> {
+    let mut targets = Vec::new();
+    for redirect in &map.redirects {
+        let Redirect { from, to, lang } = redirect;
+        if !from.starts_with('/') {
+            return Err(format!("`from` must start with `/`, got `{from}`"));
+        }
+        let route = Route::from_key(to)
+            .ok_or_else(|| format!("`{from}` redirects to `{to}`, which is not a page"))?;
+        if !site.has_language(lang) {
+            return Err(format!(
+                "`{from}` redirects to `{lang}`, which is not in the `languages` of site.toml"
+            ));
+        }
+        targets.push((from.as_str(), route, lang.as_str()));
+    }
+    if let Some(slug) = latest_tmil {
+        targets.push((LATEST_TMIL_PATH, Route::BlogPost(slug), "en"));
+    }
+
+    let mut output: Vec<OutputPage> = Vec::new();
+    let mut host_redirects = String::new();
+    for (from, route, lang_code) in targets {
+        let path = output_path(from);
+        if pages.iter().chain(&output).any(|(page, _)| *page == path) {
+            return Err(format!("`{from}` is already a page; it cannot redirect"));
+        }
+        let href = route.href(site, lang_code);
+        output.push((path, redirect_page(&href, &route.url(site, lang_code))));
+        host_redirects.push_str(&format!("{} {href} 301\n", site.href(from)));
+    }
+    if map.host_files {
+        output.push(("_redirects".to_string(), host_redirects));
+        output.push(("_headers".to_string(), headers(site)));
+    }
+    Ok(output)
+}
+
+/// Redirect pages of `redirects.toml` and the short links, and the host files
+/// it asks for. A redirect may not replace one of the generated `pages`.
+pub fn redirect_pages(site: &SiteConfig, pages: &[OutputPage]) -> Result<Vec<OutputPage>, String> {
+    let raw = fs::read_to_string(REDIRECTS_PATH)
+        .map_err(|e| format!("Failed to read {REDIRECTS_PATH}: {e}"))?;
+    let map: RedirectMap = toml::from_str(&raw).map_err(|e| format!("{REDIRECTS_PATH}: {e}"))?;
+    let latest_tmil = site.sections.blog.then(latest_tmil).flatten();
+    build_redirects(site, &map, latest_tmil.as_deref(), pages)
+        .map_err(|e| format!("{REDIRECTS_PATH}: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{REDIRECTS_PATH, RedirectMap, build_redirects, latest_tmil};
+    use crate::{config::SiteConfig, routes::Route};
+
+    #[test]
+    fn redirects_become_stubs_and_host_rules() {
+        let site = SiteConfig::load().unwrap();
+        let committed: RedirectMap =
+            toml::from_str(&std::fs::read_to_string(REDIRECTS_PATH).unwrap()).unwrap();
+        assert!(!committed.host_files);
+
+        let latest = latest_tmil().unwrap();
+        assert_eq!(latest, "2026/2026_04_tmil");
+        let map = |raw: &str| toml::from_str::<RedirectMap>(raw).unwrap();
+        let pages = vec![(Route::Roadmap.path(&site, "en"), String::new())];
+        let output = build_redirects(
+            &site,
+            &map(r#"
+                host_files = true
+                [[redirect]]
+                from = "/blog/2026/2026_04_this_month.html"
+                to = "blog/2026/2026_04_tmil"
+                lang = "pt-br"
+                "#),
+            Some(&latest),
+            &pages,
+        )
+        .unwrap();
+        let file = |path: &str| {
+            output
+                .iter()
+                .find(|(page, _)| page == path)
+                .map(|(_, contents)| contents.as_str())
+                .unwrap_or_else(|| panic!("no {path}"))
+        };
+        assert!(
+            file("blog/2026/2026_04_this_month.html")
+                .contains(r#"content="0; url=/blog/2026/2026_04_tmil.pt-br.html""#)
+        );
+        assert!(file("tmil/latest/index.html").contains(
+            r#"rel="canonical" href="https://lince.social/blog/2026/2026_04_tmil.html""#
+        ));
+        assert_eq!(
+            file("_redirects"),
+            "/blog/2026/2026_04_this_month.html /blog/2026/2026_04_tmil.pt-br.html 301\n\
+             /tmil/latest /blog/2026/2026_04_tmil.html 301\n"
+        );
+        assert!(file("_headers").starts_with("/assets/og/*\n  Cache-Control: "));
+
+        let broken = |raw: &str| build_redirects(&site, &map(raw), None, &pages).unwrap_err();
+        let redirect = "[[redirect]]\nfrom = \"/old.html\"\n";
+        assert!(broken(&format!("{redirect}to = \"about\"")).contains("not a page"));
+        assert!(broken(&format!("{redirect}to = \"blog\"\nlang = \"fr\"")).contains("`fr`"));
+        assert!(
+            broken("[[redirect]]\nfrom = \"/roadmap.html\"\nto = \"blog\"")
+                .contains("already a page")
+        );
+    }
+}
@@ -0,0 +1,410 @@
+//! The roadmap checklist every TMIL carries in `#let roadmap_items`
+//! (`[ ]`, `[/]` and `[x]` items from the `cheq` package), parsed into a tree.
+
+use crate::pages::blog::{
+    BLOG_POSTS_ROOT, collect_blog_post_files, should_skip_blog_post, slug_from_path, tmil_month,
+};
+use std::{fs, path::Path};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ItemState {
+    Todo,
+    InProgress,
+    Done,
+}
+
+impl ItemState {
+    fn parse(mark: &str) -> Option<Self> {
+        match mark {
+            "[ ]" => Some(Self::Todo),
+            "[/]" => Some(Self::InProgress),
+            "[x]" | "[X]" => Some(Self::Done),
+            _ => None,
+        }
+    }
+
+    /// Share of the work an item in this state counts as done.
+    fn progress(self) -> f64 {
+        match self {
+            Self::Todo => 0.0,
+            Self::InProgress => 0.5,
+            Self::Done => 1.0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoadmapItem {
+    pub text: String,
+    pub state: ItemState,
+    pub children: Vec<RoadmapItem>,
+}
+
+impl RoadmapItem {
+    /// Identity of an item across months: the text before the first `:`, so
+    /// a description can be reworded without the item counting as new.
+    pub fn key(&self) -> String {
+        self.text
+            .split(':')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_lowercase()
+    }
+
+    /// Completion of the subtree in percent; leaves in progress count half.
+    pub fn progress(&self) -> u32 {
+        let (done, leaves) = self.leaf_progress();
+        (done / leaves as f64 * 100.0).round() as u32
+    }
+
+    fn leaf_progress(&self) -> (f64, usize) {
+        if self.children.is_empty() {
+            return (self.state.progress(), 1);
+        }
+        self.children
+            .iter()
+            .map(RoadmapItem::leaf_progress)
+            .fold((0.0, 0), |(done, leaves), (d, l)| (done + d, leaves + l))
+    }
+}
+
+/// Roadmap of one TMIL month.
+pub struct MonthRoadmap {
+    pub slug: String,
+    pub year: u32,
+    pub month: u32,
+    pub items: Vec<RoadmapItem>,
+}
+
+impl MonthRoadmap {
+    /// `YYYY-MM`, as TMIL titles show the month.
+    pub fn label(&self) -> String {
+        format!("{}-{:02}", self.year, self.month)
+    }
+}
+
+/// The `#let roadmap_items = (` … `)` block, delimited the way the `new-tmil`
+/// task copies it forward.
+fn roadmap_block(source: &str) -> Option<&str> {
+    let start = source.find("#let roadmap_items = (")?;
+    let end = source[start..]
+        .match_indices('\n')
+        .map(|(pos, _)| start + pos + 1)
+        .find(|&line_start| source[line_start..].lines().next() == Some(")"))?;
+    Some(&source[start..end])
+}
+
+/// Parse the roadmap checklist of a TMIL source. Nesting follows indentation;
+/// lines that are not list items continue the previous item's text.
+pub fn parse_roadmap(source: &str) -> Vec<RoadmapItem> {
+    let Some(block) = roadmap_block(source) else {
+        return Vec::new();
+    };
+
+    // (indent, item) of the open items, innermost last.
+    let mut stack: Vec<(usize, RoadmapItem)> = Vec::new();
+    let mut roots = Vec::new();
+    for line in block.lines() {
+        let content = line.trim_start();
+        let indent = line.len() - content.len();
+        let content = content.trim_end().trim_end_matches('\\').trim_end();
+
+        let item = content
+            .strip_prefix("- ")
+            .filter(|rest| rest.len() >= 3 && rest.is_char_boundary(3))
+            .and_then(|rest| {
+                let state = ItemState::parse(&rest[..3])?;
+                Some(RoadmapItem {
+                    text: rest[3..].trim().to_string(),
+                    state,
+                    children: Vec::new(),
+                })
+            });
+        let Some(item) = item else {
+            if let Some((open_indent, open)) = stack.last_mut()
+                && indent > *open_indent
+                && !content.is_empty()
+            {
+                open.text.push(' ');
+                open.text.push_str(content);
+            }
+            continue;
+        };
+
+        close_items(&mut stack, &mut roots, indent);
+        stack.push((indent, item));
+    }
+    close_items(&mut stack, &mut roots, 0);
+    roots
+}
+
+/// Pop every open item indented at least `indent` into its parent.
+fn close_items(stack: &mut Vec<(usize, RoadmapItem)>, roots: &mut Vec<RoadmapItem>, indent: usize) {
+    while stack.last().is_some_and(|(open, _)| *open >= indent) {
+        let (_, item) = stack.pop().unwrap();
+        match stack.last_mut() {
+            Some((_, parent)) => parent.children.push(item),
+            None => roots.push(item),
+        }
+    }
+}
+
+/// Roadmaps of every TMIL post, oldest month first.
+pub fn tmil_roadmaps() -> Vec<MonthRoadmap> {
+    let mut files = Vec::new();
+    collect_blog_post_files(Path::new(BLOG_POSTS_ROOT), &mut files);
+
+    let mut roadmaps: Vec<MonthRoadmap> = files
+        .iter()
+        .filter_map(|file_path| {
+            let stem = file_path.file_stem()?.to_str()?;
+            if should_skip_blog_post(stem) {
+                return None;
+            }
+            let (year, month) = tmil_month(stem)?;
+            let items = parse_roadmap(&fs::read_to_string(file_path).ok()?);
+            Some(MonthRoadmap {
+                slug: slug_from_path(file_path)?,
+                year,
+                month,
+                items,
+            })
+        })
+        .collect();
+    roadmaps.sort_by_key(|roadmap| (roadmap.year, roadmap.month));
+    roadmaps
+}
+
+/// Months in which the item at `path` (keys from the root) appeared or
+/// changed state, oldest first.
+pub fn item_history<'a>(roadmaps: &'a [MonthRoadmap], path: &[String]) -> Vec<&'a MonthRoadmap> {
+    let mut changes = Vec::new();
+    let mut previous = None;
+    for roadmap in roadmaps {
+        let state = find_item(&roadmap.items, path).map(|item| item.state);
+        if state.is_some() && state != previous {
+            changes.push(roadmap);
+        }
+        previous = state;
+    }
+    changes
+}
+
+/// The item reached by following `path` of keys from `items`.
+pub fn find_item<'a>(items: &'a [RoadmapItem], path: &[String]) -> Option<&'a RoadmapItem> {
+    let (first, rest) = path.split_first()?;
+    let item = items.iter().find(|item| item.key() == *first)?;
+    if rest.is_empty() {
+        Some(item)
+    } else {
+        find_item(&item.children, rest)
+    }
+}
+
+/// How an item changed from one month's roadmap to the next. `context` holds
+/// the titles of the enclosing items, outermost first.
+#[derive(Debug, PartialEq)]
+pub enum RoadmapChange {
+    Added {
+        context: Vec<String>,
+        text: String,
+        state: ItemState,
+    },
+    Removed {
+        context: Vec<String>,
+        text: String,
+    },
+    Moved {
+        context: Vec<String>,
+        text: String,
+        from: ItemState,
+        to: ItemState,
+    },
+}
+
+/// Item title without its description, as written.
+fn title(item: &RoadmapItem) -> String {
+    item.text
+        .split(':')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_string()
+}
+
+/// Changes from `previous` to `current`. An added or removed item is reported
+/// once, not once per item below it.
+pub fn diff_roadmaps(previous: &[RoadmapItem], current: &[RoadmapItem]) -> Vec<RoadmapChange> {
+    let mut changes = Vec::new();
+    diff_items(previous, current, &mut Vec::new(), &mut changes);
+    changes
+}
+
+fn diff_items(
+    previous: &[RoadmapItem],
+    current: &[RoadmapItem],
+    context: &mut Vec<String>,
+    changes: &mut Vec<RoadmapChange>,
+) {
+    for item in current {
+        let Some(before) = previous.iter().find(|p| p.key() == item.key()) else {
+            changes.push(RoadmapChange::Added {
+                context: context.clone(),
+                text: item.text.clone(),
+                state: item.state,
+            });
+            continue;
+        };
+        if before.state != item.state {
+            changes.push(RoadmapChange::Moved {
+                context: context.clone(),
+                text: item.text.clone(),
+                from: before.state,
+                to: item.state,
+            });
+        }
+        context.push(title(item));
+        diff_items(&before.children, &item.children, context, changes);
+        context.pop();
+    }
+    for item in previous {
+        if !current.iter().any(|c| c.key() == item.key()) {
+            changes.push(RoadmapChange::Removed {
+                context: context.clone(),
+                text: item.text.clone(),
+            });
+        }
+    }
+}
+
+/// Changes of the TMIL `slug`'s roadmap since the previous TMIL's, or `None`
+/// when there is nothing to compare with.
+pub fn roadmap_changes(roadmaps: &[MonthRoadmap], slug: &str) -> Option<Vec<RoadmapChange>> {
+    let index = roadmaps.iter().position(|r| r.slug == slug)?;
+    let current = &roadmaps[index];
+    let previous = roadmaps[..index]
+        .iter()
+        .rev()
+        .find(|r| !r.items.is_empty())?;
+    if current.items.is_empty() {
+        return None;
+    }
+    Some(diff_roadmaps(&previous.items, &current.items))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ItemState, RoadmapChange, item_history, parse_roadmap, roadmap_changes, tmil_roadmaps,
+    };
+
+    #[test]
+    fn roadmap_checklists_are_parsed_into_trees() {
+        let source = std::fs::read_to_string("content/blog/posts/2025/2025_12_tmil.typ").unwrap();
+        let roadmap = parse_roadmap(&source);
+        assert_eq!(roadmap.len(), 3);
+
+        let v1 = &roadmap[0];
+        assert_eq!(v1.text, "v1.0.0: Todo Rewrite of Frontend in GPUI");
+        assert_eq!(v1.state, ItemState::InProgress);
+        assert_eq!(v1.children.len(), 3);
+        let calendar = &v1.children[0].children[2];
+        assert_eq!(calendar.key(), "calendar");
+        assert_eq!(calendar.children.len(), 1);
+
+        // Todo: Table in progress (0.5) out of Table, Kanban and one calendar leaf.
+        assert_eq!(v1.children[0].progress(), 17);
+        assert_eq!(roadmap[2].progress(), 0);
+    }
+
+    #[test]
+    fn item_history_links_the_months_where_an_item_changed() {
+        let roadmaps = tmil_roadmaps();
+        let labels: Vec<String> = roadmaps.iter().map(|r| r.label()).collect();
+        assert_eq!(
+            labels,
+            ["2025-12", "2026-01", "2026-02", "2026-03", "2026-04"]
+        );
+
+        let path = |keys: &[&str]| keys.iter().map(|k| k.to_string()).collect::<Vec<_>>();
+        // Kanban went from [ ] to [/] in 2026-03.
+        let kanban: Vec<String> = item_history(&roadmaps, &path(&["v1.0.0", "todo", "kanban"]))
+            .iter()
+            .map(|r| r.label())
+            .collect();
+        assert_eq!(kanban, ["2025-12", "2026-03"]);
+        // "Creating components" first appears in 2026-03.
+        let components: Vec<String> =
+            item_history(&roadmaps, &path(&["ai", "creating components"]))
+                .iter()
+                .map(|r| r.label())
+                .collect();
+        assert_eq!(components, ["2026-03"]);
+    }
+
+    #[test]
+    fn roadmap_diffs_follow_the_tmil_months() {
+        let roadmaps = tmil_roadmaps();
+        let changes = |slug: &str| roadmap_changes(&roadmaps, slug);
+        let context = |keys: &[&str]| keys.iter().map(|k| k.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            changes("2025/2025_12_tmil"),
+            None,
+            "The first TMIL has no previous month"
+        );
+        assert_eq!(changes("2026/2026_01_tmil"), Some(Vec::new()));
+        assert_eq!(changes("2026/2026_02_tmil"), Some(Vec::new()));
+        assert_eq!(changes("2026/2026_04_tmil"), Some(Vec::new()));
+
+        let march = changes("2026/2026_03_tmil").unwrap();
+        let moved = |keys: &[&str], text: &str, from, to| RoadmapChange::Moved {
+            context: context(keys),
+            text: text.to_string(),
+            from,
+            to,
+        };
+        for expected in [
+            moved(
+                &["v1.0.0", "Todo"],
+                "Kanban: Built for great vision of projects and tasks.",
+                ItemState::Todo,
+                ItemState::InProgress,
+            ),
+            moved(
+                &["v1.0.0", "Todo"],
+                "Table: The most basic viewing experience. From direct 1-to-1 matching of database data to combined sources into one columnn.",
+                ItemState::InProgress,
+                ItemState::Todo,
+            ),
+            moved(&["v1.0.0"], "Connection", ItemState::Todo, ItemState::InProgress),
+            moved(
+                &["v1.0.0", "Connection"],
+                "CRUD of cells (your node) and organs (group of nodes).",
+                ItemState::Todo,
+                ItemState::Done,
+            ),
+            RoadmapChange::Added {
+                context: Vec::new(),
+                text: "Minor Version: Stock & Orders Management".to_string(),
+                state: ItemState::Todo,
+            },
+            RoadmapChange::Removed {
+                context: Vec::new(),
+                text: "v1.2.0: Stock".to_string(),
+            },
+            RoadmapChange::Removed {
+                context: context(&["v1.0.0", "Todo", "Calendar"]),
+                text: "Shows Records changing with Karma. If they have a time cost, it occupies time from the calendar.".to_string(),
+            },
+        ] {
+            assert!(march.contains(&expected), "Missing {expected:?} in {march:#?}");
+        }
+        // Children of a new item are not reported on their own.
+        assert!(!march.iter().any(|change| matches!(
+            change,
+            RoadmapChange::Added { text, .. } if text == "Creating components"
+        )));
+    }
+}
@@ -0,0 +1,244 @@
+//! Every kind of page the site generates. Pages link to a `Route` instead of
+//! formatting file names, so the output path, the href in each language, the
+//! active navbar item and the language switcher all come from one place.
+
+use crate::config::{SiteConfig, UrlScheme};
+
+/// A generated page. Routes without a parameter cannot point anywhere that is
+/// not built; the slugs of the others are checked against the generated pages
+/// by `html::check_links` on every build.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Route<'a> {
+    Index,
+    VisualIdentity,
+    Blog,
+    Roadmap,
+    /// A post or year in review, by slug (`2026/2026_04_tmil`).
+    BlogPost(&'a str),
+    /// A series landing page, by series name (`tmil`).
+    Series(&'a str),
+    /// An author page, by `slugify`d name.
+    Author(&'a str),
+    /// The slide deck of a TMIL, by month (`2026/04`).
+    SlideDeck(&'a str),
+}
+
+/// Navbar item a route highlights.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NavItem {
+    Home,
+    Blog,
+}
+
+/// File name suffix of a language's pages; English pages have none.
+pub fn lang_suffix(lang_code: &str) -> &'static str {
+    match lang_code {
+        "en" => "",
+        "pt-br" => ".pt-br",
+        _ => ".zh",
+    }
+}
+
+/// `2026/2026_04_tmil` → `2026/04/tmil`: the date in a post's file name
+/// becomes directories, without repeating the year of its folder.
+fn pretty_slug(slug: &str) -> String {
+    let (folder, stem) = slug.rsplit_once('/').unwrap_or(("", slug));
+    let parts: Vec<&str> = stem.split('_').collect();
+    let dated = parts
+        .iter()
+        .take_while(|part| part.chars().all(|c| c.is_ascii_digit()))
+        .count()
+        .min(parts.len() - 1);
+    let mut date = &parts[..dated];
+    if folder.rsplit('/').next() == date.first().copied() {
+        date = &date[1..];
+    }
+    let name = parts[dated..].join("_");
+    [folder]
+        .into_iter()
+        .chain(date.iter().copied())
+        .chain([name.as_str()])
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+impl<'a> Route<'a> {
+    /// The route named by `key`; the inverse of `Route::key`.
+    pub fn from_key(key: &'a str) -> Option<Self> {
+        let route = match key {
+            "index" => Route::Index,
+            "visual-identity" => Route::VisualIdentity,
+            "blog" => Route::Blog,
+            "roadmap" => Route::Roadmap,
+            _ => {
+                if let Some(month) = key.strip_prefix("slides/") {
+                    Route::SlideDeck(month)
+                } else {
+                    let rest = key.strip_prefix("blog/")?;
+                    if let Some(series) = rest.strip_prefix("series/") {
+                        Route::Series(series)
+                    } else if let Some(author) = rest.strip_prefix("authors/") {
+                        Route::Author(author)
+                    } else {
+                        Route::BlogPost(rest)
+                    }
+                }
+            }
+        };
+        let is_segment = |s: &str| !s.is_empty() && s != "." && s != "..";
+        match route {
+            Route::BlogPost(slug)
+            | Route::Series(slug)
+            | Route::Author(slug)
+            | Route::SlideDeck(slug)
+                if !slug.split('/').all(is_segment) =>
+            {
+                None
+            }
+            _ => Some(route),
+        }
+    }
+
+    /// Language-independent name of the page (`index`, `blog/2026/2026_04_tmil`),
+    /// also used to name its share card.
+    pub fn key(&self) -> String {
+        match self {
+            Route::Index => "index".to_string(),
+            Route::VisualIdentity => "visual-identity".to_string(),
+            Route::Blog => "blog".to_string(),
+            Route::Roadmap => "roadmap".to_string(),
+            Route::BlogPost(slug) => format!("blog/{slug}"),
+            Route::Series(series) => format!("blog/series/{series}"),
+            Route::Author(slug) => format!("blog/authors/{slug}"),
+            Route::SlideDeck(month) => format!("slides/{month}"),
+        }
+    }
+
+    /// Path of the page under `output/` in `lang_code`.
+    pub fn path(&self, site: &SiteConfig, lang_code: &str) -> String {
+        match site.url_scheme {
+            UrlScheme::Suffix => self.suffix_path(lang_code),
+            UrlScheme::Directories => format!("{}index.html", self.directory(lang_code)),
+        }
+    }
+
+    /// Path of the page under `UrlScheme::Suffix`, which the other schemes
+    /// redirect from.
+    pub fn suffix_path(&self, lang_code: &str) -> String {
+        format!("{}{}.html", self.key(), lang_suffix(lang_code))
+    }
+
+    /// Directory of the page under `UrlScheme::Directories`, prefixed with
+    /// the language except in English: `pt-br/blog/2026/04/tmil/`, or empty
+    /// for the English home page.
+    fn directory(&self, lang_code: &str) -> String {
+        let page = match self {
+            Route::Index => String::new(),
+            Route::BlogPost(slug) => format!("blog/{}/", pretty_slug(slug)),
+            _ => format!("{}/", self.key()),
+        };
+        match lang_code {
+            "en" => page,
+            _ => format!("{lang_code}/{page}"),
+        }
+    }
+
+    /// Site path the page is linked by: its file, or its directory.
+    fn link_path(&self, site: &SiteConfig, lang_code: &str) -> String {
+        match site.url_scheme {
+            UrlScheme::Suffix => self.suffix_path(lang_code),
+            UrlScheme::Directories => self.directory(lang_code),
+        }
+    }
+
+    pub fn href(&self, site: &SiteConfig, lang_code: &str) -> String {
+        site.href(&self.link_path(site, lang_code))
+    }
+
+    /// Absolute URL, for links that leave the site.
+    pub fn url(&self, site: &SiteConfig, lang_code: &str) -> String {
+        site.url(&self.link_path(site, lang_code))
+    }
+
+    pub fn nav_item(&self) -> Option<NavItem> {
+        match self {
+            Route::Index => Some(NavItem::Home),
+            Route::Blog | Route::BlogPost(_) | Route::Series(_) | Route::Author(_) => {
+                Some(NavItem::Blog)
+            }
+            Route::VisualIdentity | Route::Roadmap | Route::SlideDeck(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NavItem, Route, pretty_slug};
+    use crate::config::{SiteConfig, UrlScheme};
+
+    #[test]
+    fn routes_map_to_localized_paths_and_nav_items() {
+        let site = SiteConfig::load().unwrap();
+        assert_eq!(Route::Index.path(&site, "en"), "index.html");
+        assert_eq!(
+            Route::BlogPost("2026/2026_04_tmil").href(&site, "pt-br"),
+            "/blog/2026/2026_04_tmil.pt-br.html"
+        );
+        assert_eq!(
+            Route::Author("duds").url(&site, "zh"),
+            "https://lince.social/blog/authors/duds.zh.html"
+        );
+        assert_eq!(Route::Series("tmil").nav_item(), Some(NavItem::Blog));
+        assert_eq!(Route::Roadmap.nav_item(), None);
+        assert_eq!(
+            Route::SlideDeck("2026/04").path(&site, "zh"),
+            "slides/2026/04.zh.html"
+        );
+
+        for route in [
+            Route::Index,
+            Route::Roadmap,
+            Route::BlogPost("2026/2026_04_tmil"),
+            Route::Series("tmil"),
+            Route::Author("duds"),
+            Route::SlideDeck("2026/04"),
+        ] {
+            assert_eq!(Route::from_key(&route.key()), Some(route));
+        }
+        assert_eq!(Route::from_key("blog/2026/"), None);
+        assert_eq!(Route::from_key("slides/2026/.."), None);
+        assert_eq!(Route::from_key("about"), None);
+    }
+
+    #[test]
+    fn directory_scheme_prefixes_languages_and_links_directories() {
+        let mut site = SiteConfig::load().unwrap();
+        site.url_scheme = UrlScheme::Directories;
+        let post = Route::BlogPost("2026/2026_04_tmil");
+        assert_eq!(
+            post.path(&site, "pt-br"),
+            "pt-br/blog/2026/04/tmil/index.html"
+        );
+        assert_eq!(post.href(&site, "pt-br"), "/pt-br/blog/2026/04/tmil/");
+        assert_eq!(
+            post.suffix_path("pt-br"),
+            "blog/2026/2026_04_tmil.pt-br.html"
+        );
+        assert_eq!(Route::Index.path(&site, "en"), "index.html");
+        assert_eq!(Route::Index.href(&site, "en"), "/");
+        assert_eq!(Route::Index.href(&site, "zh"), "/zh/");
+        assert_eq!(
+            Route::Series("tmil").href(&site, "en"),
+            "/blog/series/tmil/"
+        );
+        assert_eq!(
+            Route::SlideDeck("2026/04").href(&site, "pt-br"),
+            "/pt-br/slides/2026/04/"
+        );
+
+        assert_eq!(pretty_slug("2026/2026_review"), "2026/review");
+        assert_eq!(pretty_slug("2026/2026_05_14_launch"), "2026/05/14/launch");
+        assert_eq!(pretty_slug("hello_world"), "hello_world");
+    }
+}
@@ -0,0 +1,126 @@
+//! Open Graph share cards: a 1200×630 PNG per post and top-level page,
+//! rendered from `content/share-card.typ` and named by fingerprint, so
+//! unchanged cards are not rendered again and changed ones get a new URL.
+
+use crate::{
+    cache::stable_hash,
+    compiler::{TYPST_LIBRARY_VERSION, TypstBackend, default_backend},
+};
+use std::{fs, path::Path};
+
+const SHARE_CARD_TEMPLATE: &str = "content/share-card.typ";
+/// Read by the template; part of every card's fingerprint.
+const SHARE_CARD_LOGO: &str = "output/assets/logo/white.svg";
+const SHARE_CARD_DIR: &str = "output/assets/og";
+pub const SHARE_CARD_PUBLIC_DIR: &str = "/assets/og";
+pub const SHARE_CARD_WIDTH: u32 = 1200;
+pub const SHARE_CARD_HEIGHT: u32 = 630;
+/// The template page is 600×315pt.
+const PIXELS_PER_PT: f32 = 2.0;
+
+/// Text of a card. Cards are shared by every language of a page: the
+/// in-process Typst has no CJK fonts, so they use the source's own wording.
+pub struct ShareCard<'a> {
+    pub title: &'a str,
+    /// Small line above the title (series or tagline).
+    pub kicker: Option<&'a str>,
+    pub date: Option<&'a str>,
+}
+
+impl ShareCard<'_> {
+    fn inputs(&self) -> Vec<(&str, &str)> {
+        let mut inputs = vec![("title", self.title)];
+        inputs.extend(self.kicker.map(|kicker| ("kicker", kicker)));
+        inputs.extend(self.date.map(|date| ("date", date)));
+        inputs
+    }
+
+    /// Hash of the template, the logo, the inputs and the linked Typst
+    /// version: everything that shows on the card, and nothing that differs
+    /// between machines, so every build names an unchanged card the same.
+    fn fingerprint(&self) -> Option<u64> {
+        let template = fs::read(SHARE_CARD_TEMPLATE).ok()?;
+        let logo = fs::read(SHARE_CARD_LOGO).ok()?;
+        let inputs = self.inputs();
+        let parts = [template.as_slice(), &logo, TYPST_LIBRARY_VERSION.as_bytes()]
+            .into_iter()
+            .chain(
+                inputs
+                    .iter()
+                    .flat_map(|(key, value)| [key.as_bytes(), value.as_bytes()]),
+            );
+        Some(stable_hash(parts))
+    }
+}
+
+/// Delete earlier cards of `key`, keeping `current`.
+fn remove_superseded(key: &str, current: &str) {
+    let Ok(entries) = fs::read_dir(SHARE_CARD_DIR) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name != current && name.rsplit_once('-').is_some_and(|(stem, _)| stem == key) {
+            let _ = fs::remove_file(entry.path());
+        }
+    }
+}
+
+/// Public href of the card of page `key` (`index`, `blog/2026/2026_04_tmil`),
+/// rendering it first unless a card with the same fingerprint exists.
+pub fn share_card(key: &str, card: &ShareCard) -> Option<String> {
+    let key = key.replace('/', "__");
+    let name = format!("{key}-{:016x}.png", card.fingerprint()?);
+    let path = Path::new(SHARE_CARD_DIR).join(&name);
+    if !path.exists() {
+        let png = default_backend()
+            .compile_png(
+                Path::new(SHARE_CARD_TEMPLATE),
+                &card.inputs(),
+                PIXELS_PER_PT,
+            )
+            .map_err(|err| eprintln!("No share card for {key}:\n{err}"))
+            .ok()?;
+        fs::create_dir_all(SHARE_CARD_DIR).ok()?;
+        fs::write(&path, png).ok()?;
+        remove_superseded(&key, &name);
+    }
+    Some(format!("{SHARE_CARD_PUBLIC_DIR}/{name}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        PIXELS_PER_PT, SHARE_CARD_HEIGHT, SHARE_CARD_TEMPLATE, SHARE_CARD_WIDTH, ShareCard,
+    };
+    use crate::compiler::{InProcessBackend, TypstBackend};
+    use std::path::Path;
+
+    #[test]
+    fn share_cards_are_rendered_at_open_graph_size() {
+        let card = ShareCard {
+            title: "This Month in Lince | 2026-04",
+            kicker: Some("This Month in Lince"),
+            date: Some("2026-05-01"),
+        };
+        let png = InProcessBackend::new(".")
+            .compile_png(
+                Path::new(SHARE_CARD_TEMPLATE),
+                &card.inputs(),
+                PIXELS_PER_PT,
+            )
+            .unwrap_or_else(|e| panic!("Failed to render the share card: {e}"));
+        let image = image::load_from_memory(&png).unwrap();
+        assert_eq!(
+            (image.width(), image.height()),
+            (SHARE_CARD_WIDTH, SHARE_CARD_HEIGHT)
+        );
+
+        let other = ShareCard {
+            title: "Roadmap",
+            kicker: None,
+            date: None,
+        };
+        assert_ne!(card.fingerprint(), other.fingerprint());
+    }
+}
@@ -0,0 +1,594 @@
+//! Build-time post-processing of the Tinymist/Typst SVG sidecars embedded in blog posts.
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use std::{
+    collections::{BTreeMap, hash_map::DefaultHasher},
+    fs,
+    hash::{Hash, Hasher},
+    io,
+    path::Path,
+};
+
+/// Vertical space left between stacked pages, in SVG user units.
+const PAGE_GAP: f64 = 10.0;
+
+/// Space kept below a page's last text baseline, for descenders.
+const DESCENDER_ROOM: f64 = 8.0;
+
+/// Base64 image payloads up to this many bytes stay inline; larger ones become files.
+pub const INLINE_IMAGE_LIMIT: usize = 4 * 1024;
+
+/// Preview-only decorations that never belong in a published post.
+const DECORATION_CLASSES: [&str; 4] = [
+    "typst-page-outer",
+    "typst-page-inner",
+    "typst-preview-canvas-page-number",
+    "typst-preview-svg-page-number",
+];
+
+/// Value of `name="..."` inside a single start tag.
+pub fn attr<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let marker = format!(" {name}=\"");
+    let start = tag.find(&marker)? + marker.len();
+    let end = tag[start..].find('"')?;
+    Some(&tag[start..start + end])
+}
+
+/// Replace (or add) `name="value"` in a single start tag.
+pub fn set_attr(tag: &str, name: &str, value: &str) -> String {
+    let without = remove_attr(tag, name);
+    let insert_at = if without.ends_with("/>") {
+        without.len() - 2
+    } else {
+        without.len() - 1
+    };
+    format!(
+        "{} {name}=\"{value}\"{}",
+        without[..insert_at].trim_end(),
+        &without[insert_at..]
+    )
+}
+
+pub fn remove_attr(tag: &str, name: &str) -> String {
+    let marker = format!(" {name}=\"");
+    let Some(start) = tag.find(&marker) else {
+        return tag.to_string();
+    };
+    let value_start = start + marker.len();
+    let Some(value_len) = tag[value_start..].find('"') else {
+        return tag.to_string();
+    };
+    format!("{}{}", &tag[..start], &tag[value_start + value_len + 1..])
+}
+
+fn has_class(tag: &str, class: &str) -> bool {
+    attr(tag, "class").is_some_and(|classes| classes.split_whitespace().any(|c| c == class))
+}
+
+fn tag_name(tag: &str) -> &str {
+    let name = &tag[1..];
+    let end = name
+        .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+        .unwrap_or(name.len());
+    &name[..end]
+}
+
+/// End (exclusive) of the start tag beginning at `start`.
+pub fn start_tag_end(input: &str, start: usize) -> Option<usize> {
+    input[start..].find('>').map(|p| start + p + 1)
+}
+
+/// End (exclusive) of the whole element whose start tag begins at `start`,
+/// counting nested elements of the same name.
+pub fn element_end(input: &str, start: usize) -> Option<usize> {
+    let tag_end = start_tag_end(input, start)?;
+    if input[start..tag_end].ends_with("/>") {
+        return Some(tag_end);
+    }
+    let name = tag_name(&input[start..tag_end]);
+    let open = format!("<{name}");
+    let close = format!("</{name}>");
+    let mut depth = 1usize;
+    let mut cursor = tag_end;
+
+    while depth > 0 {
+        let next_close = input[cursor..].find(&close).map(|p| cursor + p)?;
+        let next_open = input[cursor..next_close]
+            .match_indices(&open)
+            .map(|(p, _)| cursor + p)
+            .find(|&p| {
+                let after = input[p + open.len()..].chars().next();
+                matches!(after, Some(c) if c.is_whitespace() || c == '>' || c == '/')
+                    && !input[p..start_tag_end(input, p).unwrap_or(p)].ends_with("/>")
+            });
+        match next_open {
+            Some(open_at) => {
+                depth += 1;
+                cursor = start_tag_end(input, open_at)?;
+            }
+            None => {
+                depth -= 1;
+                cursor = next_close + close.len();
+            }
+        }
+    }
+
+    Some(cursor)
+}
+
+/// Start offsets of every start tag whose class list contains `class`.
+fn find_tags_with_class(input: &str, class: &str) -> Vec<usize> {
+    let mut found = Vec::new();
+    let mut cursor = 0;
+    while let Some(rel) = input[cursor..].find('<') {
+        let start = cursor + rel;
+        let Some(end) = start_tag_end(input, start) else {
+            break;
+        };
+        let tag = &input[start..end];
+        if !tag.starts_with("</") && has_class(tag, class) {
+            found.push(start);
+        }
+        cursor = start + 1;
+    }
+    found
+}
+
+fn remove_elements_with_class(input: &str, class: &str) -> String {
+    let mut out = input.to_string();
+    for start in find_tags_with_class(input, class).into_iter().rev() {
+        if let Some(end) = element_end(&out, start) {
+            out.replace_range(start..end, "");
+        }
+    }
+    out
+}
+
+/// Vertical offset of a `matrix(a b c d e f)` or `translate(x, y)` transform
+/// (the first one, when several are chained).
+fn transform_y(transform: &str) -> Option<f64> {
+    let (kind, args) = transform.trim().split_once('(')?;
+    let values: Vec<f64> = args
+        .split(')')
+        .next()?
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|v| !v.is_empty())
+        .filter_map(|v| v.parse().ok())
+        .collect();
+    match kind.trim() {
+        "matrix" if values.len() == 6 => Some(values[5]),
+        "translate" => Some(values.get(1).copied().unwrap_or(0.0)),
+        _ => None,
+    }
+}
+
+fn number(value: f64) -> String {
+    let rounded = format!("{value:.3}");
+    rounded
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+/// Lowest point drawn in `fragment`, in its own coordinates: the deepest
+/// glyph baseline or image bottom, following nested `translate`/`matrix`
+/// offsets (Typst does not scale the groups of a page). `None` when nothing is
+/// drawn.
+fn content_bottom(fragment: &str) -> Option<f64> {
+    let mut offsets: Vec<f64> = Vec::new();
+    let mut bottom: Option<f64> = None;
+    let mut cursor = 0;
+    while let Some(rel) = fragment[cursor..].find('<') {
+        let start = cursor + rel;
+        let Some(end) = start_tag_end(fragment, start) else {
+            break;
+        };
+        cursor = end;
+        let tag = &fragment[start..end];
+        if tag.starts_with("</") {
+            offsets.pop();
+            continue;
+        }
+        let y = offsets.last().copied().unwrap_or(0.0)
+            + attr(tag, "transform").and_then(transform_y).unwrap_or(0.0);
+        let drawn = match tag_name(tag) {
+            "use" => Some(attr(tag, "y").and_then(|v| v.parse().ok()).unwrap_or(0.0)),
+            "image" | "rect" => attr(tag, "height").and_then(|v| v.parse::<f64>().ok()),
+            "path" => Some(0.0),
+            _ => None,
+        };
+        if let Some(extent) = drawn {
+            bottom = Some(bottom.map_or(y + extent, |b| b.max(y + extent)));
+        }
+        if !tag.ends_with("/>") {
+            offsets.push(y);
+        }
+    }
+    bottom
+}
+
+/// Lay out a Typst/Tinymist document SVG at build time: drop preview page
+/// decorations, stack `g.typst-page` groups without their blank top and
+/// bottom bands, size the `viewBox` to the result and make the root SVG fluid.
+pub fn layout_typst_svg(doc_svg: &str) -> String {
+    let mut svg = doc_svg.to_string();
+    for class in DECORATION_CLASSES {
+        svg = remove_elements_with_class(&svg, class);
+    }
+
+    let mut edits: Vec<(usize, usize, String)> = Vec::new();
+    let mut y = 0.0_f64;
+    let mut width: Option<f64> = None;
+
+    for start in find_tags_with_class(&svg, "typst-page") {
+        let (Some(tag_end), Some(page_end)) =
+            (start_tag_end(&svg, start), element_end(&svg, start))
+        else {
+            continue;
+        };
+        let tag = &svg[start..tag_end];
+        let page_width = attr(tag, "data-page-width").and_then(|v| v.parse::<f64>().ok());
+        let page_height = attr(tag, "data-page-height")
+            .and_then(|v| v.parse::<f64>().ok())
+            .unwrap_or(0.0);
+        width = width.or(page_width);
+
+        // Content starts where Typst placed the page's first content group
+        // (i.e. after the top margin); everything above it is blank.
+        let content_top = find_tags_with_class(&svg[tag_end..page_end], "typst-group")
+            .first()
+            .and_then(|&rel| {
+                let group_start = tag_end + rel;
+                let group_tag = &svg[group_start..start_tag_end(&svg, group_start)?];
+                attr(group_tag, "transform").and_then(transform_y)
+            })
+            .filter(|top| *top > 0.0 && *top < page_height)
+            .unwrap_or(0.0);
+
+        // Content ends at the lowest thing drawn; the bottom margin and any
+        // trailing blank space below it are dropped too.
+        let content_bottom = content_bottom(&svg[tag_end..page_end])
+            .map(|bottom| (bottom + DESCENDER_ROOM).min(page_height))
+            .filter(|bottom| *bottom > content_top)
+            .unwrap_or(page_height);
+
+        let translate = format!("translate(0, {})", number(y - content_top));
+        edits.push((start, tag_end, set_attr(tag, "transform", &translate)));
+        y += (content_bottom - content_top) + PAGE_GAP;
+    }
+
+    for (start, end, tag) in edits.into_iter().rev() {
+        svg.replace_range(start..end, &tag);
+    }
+
+    let Some(root_start) = svg.find("<svg") else {
+        return svg;
+    };
+    let Some(root_end) = start_tag_end(&svg, root_start) else {
+        return svg;
+    };
+    let mut root = svg[root_start..root_end].to_string();
+    let width = width.or_else(|| attr(&root, "data-width").and_then(|v| v.parse().ok()));
+    if let Some(width) = width
+        && y > 0.0
+    {
+        root = set_attr(
+            &root,
+            "viewBox",
+            &format!("0 0 {} {}", number(width), number(y - PAGE_GAP)),
+        );
+    }
+    root = remove_attr(&root, "height");
+    root = set_attr(&root, "width", "100%");
+    root = set_attr(
+        &root,
+        "style",
+        "height: auto; display: block; max-width: 100%; background: transparent;",
+    );
+    svg.replace_range(root_start..root_end, &root);
+    svg
+}
+
+/// Replace base64 `data:` images in `<image>` elements with fingerprinted files
+/// under `out_dir`, so photos are fetched (and cached) separately from the post.
+pub fn externalize_images(svg: &str, out_dir: &Path, public_dir: &str) -> io::Result<String> {
+    let mut out = String::with_capacity(svg.len());
+    let mut rest = svg;
+    while let Some(pos) = rest.find("<image") {
+        out.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+        let Some(end) = start_tag_end(rest, 0) else {
+            break;
+        };
+        out.push_str(&externalize_image_tag(&rest[..end], out_dir, public_dir)?);
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+fn externalize_image_tag(tag: &str, out_dir: &Path, public_dir: &str) -> io::Result<String> {
+    let Some((name, href)) = ["xlink:href", "href"]
+        .into_iter()
+        .find_map(|name| attr(tag, name).map(|href| (name, href)))
+    else {
+        return Ok(tag.to_string());
+    };
+    let Some((mime, payload)) = href
+        .strip_prefix("data:")
+        .and_then(|data| data.split_once(";base64,"))
+    else {
+        return Ok(tag.to_string());
+    };
+    let extension = match mime {
+        "image/jpeg" => "jpg",
+        "image/png" => "png",
+        "image/gif" => "gif",
+        "image/webp" => "webp",
+        "image/svg+xml" => "svg",
+        _ => return Ok(tag.to_string()),
+    };
+    if payload.len() <= INLINE_IMAGE_LIMIT {
+        return Ok(tag.to_string());
+    }
+
+    let bytes = STANDARD
+        .decode(payload)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    let file_name = format!("image-{:016x}.{extension}", hasher.finish());
+    let target = out_dir.join(&file_name);
+    if !target.exists() {
+        fs::create_dir_all(out_dir)?;
+        fs::write(&target, bytes)?;
+    }
+
+    let tag = set_attr(tag, name, &format!("{public_dir}/{file_name}"));
+    let tag = set_attr(&tag, "loading", "lazy");
+    Ok(set_attr(&tag, "decoding", "async"))
+}
+
+/// Size of the largest base64 `data:image` payload in `html`.
+pub fn largest_inline_image(html: &str) -> usize {
+    html.match_indices("data:image/")
+        .filter_map(|(pos, _)| {
+            let data = &html[pos..];
+            let payload = &data[data.find(";base64,")? + ";base64,".len()..];
+            Some(payload.find(['"', '\'', ')']).unwrap_or(payload.len()))
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Move every glyph `<symbol id="g…">` out of `svg`. Typst derives glyph ids
+/// from the outline itself, so equal ids across posts are the same glyph.
+/// Returns the stripped SVG and the `(id, symbol markup)` pairs removed.
+pub fn split_glyphs(svg: &str) -> (String, Vec<(String, String)>) {
+    let mut out = svg.to_string();
+    let mut glyphs = Vec::new();
+    let mut cursor = 0;
+    while let Some(rel) = out[cursor..].find("<symbol") {
+        let start = cursor + rel;
+        let Some(tag_end) = start_tag_end(&out, start) else {
+            break;
+        };
+        let id = attr(&out[start..tag_end], "id").map(str::to_string);
+        let end = element_end(&out, start);
+        match (id, end) {
+            (Some(id), Some(end)) if id.starts_with('g') => {
+                glyphs.push((id, out[start..end].to_string()));
+                out.replace_range(start..end, "");
+            }
+            _ => cursor = tag_end,
+        }
+    }
+
+    // Drop the glyph `<defs>` containers emptied above.
+    while let Some(start) = out.find("<defs id=\"glyph\">") {
+        let Some(end) = element_end(&out, start) else {
+            break;
+        };
+        let tag_end = start_tag_end(&out, start).unwrap_or(end);
+        if !out[tag_end..end - "</defs>".len()].trim().is_empty() {
+            break;
+        }
+        out.replace_range(start..end, "");
+    }
+
+    (out, glyphs)
+}
+
+/// Point `href="#id"` / `xlink:href="#id"` references to glyphs of the sprite at `sprite_href`.
+pub fn link_glyphs(svg: &str, sprite_href: &str, glyphs: &BTreeMap<String, String>) -> String {
+    let mut out = String::with_capacity(svg.len());
+    let mut rest = svg;
+    while let Some(pos) = rest.find("href=\"#") {
+        let value_start = pos + "href=\"".len();
+        out.push_str(&rest[..value_start]);
+        rest = &rest[value_start..];
+        let id_end = rest.find('"').unwrap_or(rest.len());
+        if glyphs.contains_key(&rest[1..id_end]) {
+            out.push_str(sprite_href);
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Write the shared glyph sprite as `glyphs-{fingerprint}.svg` under `out_dir`,
+/// removing sprites left over from earlier builds. Returns its public href.
+///
+/// Clip paths stay inline in each post: browsers resolve `<use>` references into
+/// other documents but not `clip-path="url(other.svg#id)"`.
+pub fn write_glyph_sprite(
+    glyphs: &BTreeMap<String, String>,
+    out_dir: &Path,
+    public_dir: &str,
+) -> io::Result<String> {
+    let mut sprite = String::from(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" xmlns:xlink=\"http://www.w3.org/1999/xlink\"><defs>",
+    );
+    for symbol in glyphs.values() {
+        sprite.push_str(symbol);
+    }
+    sprite.push_str("</defs></svg>");
+
+    let mut hasher = DefaultHasher::new();
+    sprite.hash(&mut hasher);
+    let name = format!("glyphs-{:016x}.svg", hasher.finish());
+
+    fs::create_dir_all(out_dir)?;
+    for entry in fs::read_dir(out_dir)?.flatten() {
+        let file_name = entry.file_name();
+        let file_name = file_name.to_string_lossy();
+        if file_name.starts_with("glyphs-") && file_name.ends_with(".svg") && file_name != name {
+            fs::remove_file(entry.path())?;
+        }
+    }
+    let target = out_dir.join(&name);
+    if !target.exists() {
+        fs::write(&target, sprite)?;
+    }
+    Ok(format!("{public_dir}/{name}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        INLINE_IMAGE_LIMIT, attr, element_end, externalize_images, largest_inline_image,
+        layout_typst_svg, link_glyphs, split_glyphs, write_glyph_sprite,
+    };
+    use std::collections::BTreeMap;
+
+    const PAGED: &str = r##"<svg class="typst-doc" width="595pt" height="2000pt" data-width="595">
+<g class="typst-page" data-page-width="595" data-page-height="800" transform="translate(0, 0)">
+<rect class="typst-page-outer" width="595" height="800"/>
+<rect class="typst-page-inner" width="595" height="800"/>
+<g class="typst-group" transform="matrix(1 0 0 1 70 30)"><g class="typst-text" transform="matrix(1 0 0 -1 0 12)"><use href="#g1" x="0" y="0"/></g><g transform="translate(0 20)"><image width="100" height="300"/></g></g>
+<text class="typst-preview-svg-page-number">1</text>
+</g>
+<g class="typst-page" data-page-width="595" data-page-height="500" transform="translate(0, 810)">
+<g class="typst-group" transform="matrix(1 0 0 1 70 20)"><g><g></g></g></g>
+</g>
+</svg>"##;
+
+    #[test]
+    fn pages_are_stacked_without_blank_top_bands() {
+        let out = layout_typst_svg(PAGED);
+
+        for class in [
+            "typst-page-outer",
+            "typst-page-inner",
+            "typst-preview-svg-page-number",
+        ] {
+            assert!(!out.contains(class), "Decoration `{class}` was not removed");
+        }
+        // Page 1 ends below its image (350 + descender room); page 2 draws
+        // nothing, so only its top band goes.
+        assert!(out.contains(r#"data-page-height="800" transform="translate(0, -30)""#));
+        assert!(out.contains(r#"data-page-height="500" transform="translate(0, 318)""#));
+
+        let root = &out[..out.find('>').unwrap()];
+        assert_eq!(attr(root, "viewBox"), Some("0 0 595 818"));
+        assert_eq!(attr(root, "width"), Some("100%"));
+        assert_eq!(attr(root, "height"), None);
+        assert!(!out.contains("<script"));
+    }
+
+    #[test]
+    fn trailing_blank_space_is_cropped_below_the_last_line() {
+        let svg = r##"<svg class="typst-doc" data-width="595">
+<g class="typst-page" data-page-width="595" data-page-height="842">
+<g class="typst-group" transform="matrix(1 0 0 1 70 70)">
+<g class="typst-text" transform="translate(0 14) scale(1, -1)"><use href="#g1" x="0" y="0"/></g>
+<g class="typst-group" transform="translate(0, 20)"><g class="typst-text" transform="matrix(1 0 0 -1 0 14)"><use href="#g2" x="5" y="0"/></g></g>
+</g>
+</g>
+<g class="typst-page" data-page-width="595" data-page-height="842">
+<g class="typst-group" transform="matrix(1 0 0 1 70 70)"><g class="typst-text" transform="matrix(1 0 0 -1 0 14)"><use href="#g1"/></g></g>
+</g>
+</svg>"##;
+        let out = layout_typst_svg(svg);
+        // Last baseline of page 1 at 70 + 20 + 14, plus room for descenders:
+        // 42pt tall instead of the 772pt left below the top margin.
+        assert!(out.contains(r#"data-page-height="842" transform="translate(0, -70)""#));
+        assert!(out.contains(r#"data-page-height="842" transform="translate(0, -18)""#));
+        let root = &out[..out.find('>').unwrap()];
+        assert_eq!(attr(root, "viewBox"), Some("0 0 595 74"));
+    }
+
+    #[test]
+    fn single_page_typst_svg_keeps_its_viewbox() {
+        let svg = r#"<svg class="typst-doc" viewBox="0 0 595 1435" width="595pt" height="1435pt"><g class="typst-group"></g></svg>"#;
+        let out = layout_typst_svg(svg);
+        let root = &out[..out.find('>').unwrap()];
+        assert_eq!(attr(root, "viewBox"), Some("0 0 595 1435"));
+        assert_eq!(attr(root, "width"), Some("100%"));
+        assert_eq!(attr(root, "height"), None);
+    }
+
+    #[test]
+    fn element_end_matches_nested_groups() {
+        let input = "<g a=\"1\"><g/><g><g></g></g></g><g></g>";
+        assert_eq!(element_end(input, 0), Some(input.len() - "<g></g>".len()));
+    }
+
+    #[test]
+    fn glyphs_shared_between_posts_are_linked_from_one_sprite() {
+        let post = |extra: &str| {
+            format!(
+                r##"<svg class="typst-doc"><defs id="glyph"><symbol id="gA" overflow="visible"><path d="M 0 0"/></symbol>{extra}</defs><defs id="clip-path"><clipPath id="c1"><path d="M 1 1"/></clipPath></defs><g clip-path="url(#c1)"><use xlink:href="#gA"/><use href="#gB"/></g></svg>"##
+            )
+        };
+        let (first, first_glyphs) = split_glyphs(&post(""));
+        let (second, second_glyphs) = split_glyphs(&post(
+            r#"<symbol id="gB" overflow="visible"><path d="M 2 2"/></symbol>"#,
+        ));
+        assert!(!first.contains("<symbol") && !first.contains("id=\"glyph\""));
+        assert!(
+            first.contains("<clipPath id=\"c1\">"),
+            "Clip paths must stay inline"
+        );
+
+        let glyphs: BTreeMap<String, String> =
+            first_glyphs.into_iter().chain(second_glyphs).collect();
+        assert_eq!(glyphs.len(), 2, "Shared glyphs must be deduplicated");
+
+        let out_dir = std::env::temp_dir().join("lince-website-sprite-test");
+        let href = write_glyph_sprite(&glyphs, &out_dir, "/assets/blog").unwrap();
+        let sprite_file = out_dir.join(href.trim_start_matches("/assets/blog/"));
+        let sprite = std::fs::read_to_string(sprite_file).unwrap();
+        assert_eq!(sprite.matches("<symbol").count(), 2);
+
+        let linked = link_glyphs(&second, &href, &glyphs);
+        assert!(linked.contains(&format!("<use xlink:href=\"{href}#gA\"/>")));
+        assert!(linked.contains(&format!("<use href=\"{href}#gB\"/>")));
+        assert!(linked.contains("clip-path=\"url(#c1)\""));
+    }
+
+    #[test]
+    fn large_embedded_images_become_lazy_fingerprinted_files() {
+        let big = "A".repeat(INLINE_IMAGE_LIMIT + 4);
+        let svg = format!(
+            r#"<svg><image xlink:href="data:image/png;base64,{big}" width="10"/><image href="data:image/png;base64,AAAA"/></svg>"#
+        );
+        let out_dir = std::env::temp_dir().join("lince-website-embedded-image-test");
+        let out = externalize_images(&svg, &out_dir, "/assets/blog").unwrap();
+
+        assert!(largest_inline_image(&out) <= INLINE_IMAGE_LIMIT);
+        assert!(
+            out.contains(r#"href="data:image/png;base64,AAAA""#),
+            "Tiny images stay inline"
+        );
+        let start = out.find("/assets/blog/image-").unwrap();
+        let href = &out[start..start + out[start..].find('"').unwrap()];
+        assert!(href.ends_with(".png"));
+        assert!(
+            out_dir
+                .join(href.trim_start_matches("/assets/blog/"))
+                .exists()
+        );
+        assert!(out.contains(r#"loading="lazy""#) && out.contains(r#"decoding="async""#));
+    }
+}
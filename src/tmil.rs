@@ -0,0 +1,433 @@
+//! Structure of a TMIL source (`*_tmil.typ`): its `#let` settings and the
+//! `tmil_item`s of every `tmil_section`, with the line each was written on.
+
+use crate::pages::blog::tmil_month;
+use std::{fs, io, path::Path};
+
+/// Template new TMIL posts are copied from.
+pub const TMIL_TEMPLATE: &str = "content/blog/YYYY_MM_DD_tmil.typ";
+
+/// One value per TMIL language, in `tmil_tr(en, pt_br, zh_cn)` order.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Localized<T> {
+    pub en: T,
+    pub pt_br: T,
+    pub zh_cn: T,
+}
+
+impl<T> Localized<T> {
+    /// Value for a site language code (`en`, `pt-br`, `zh`).
+    pub fn get(&self, lang_code: &str) -> &T {
+        match lang_code {
+            "pt-br" => &self.pt_br,
+            "zh" => &self.zh_cn,
+            _ => &self.en,
+        }
+    }
+}
+
+/// `("Title", "Optional subtitle")` of an item in one language.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Heading {
+    pub title: String,
+    pub subtitle: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TmilItem {
+    pub line: usize,
+    /// `None` for untranslated headings such as the roadmap's `("", "")`.
+    pub heading: Option<Localized<Heading>>,
+    pub photo: Option<String>,
+    /// Typst markup of the item's content block, dedented.
+    pub body: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TmilSection {
+    pub line: usize,
+    /// As written: `pt-BR | zh-CN | en`.
+    pub name: String,
+    pub items: Vec<TmilItem>,
+}
+
+impl TmilSection {
+    /// The section name split into its languages, if it follows `pt-BR | zh-CN | en`.
+    pub fn names(&self) -> Option<Localized<String>> {
+        let parts: Vec<&str> = self.name.split('|').map(str::trim).collect();
+        let [pt_br, zh_cn, en] = parts.as_slice() else {
+            return None;
+        };
+        Some(Localized {
+            en: en.to_string(),
+            pt_br: pt_br.to_string(),
+            zh_cn: zh_cn.to_string(),
+        })
+    }
+}
+
+/// A `#let key = "value"` setting and its line.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Setting {
+    pub line: usize,
+    pub value: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TmilSource {
+    /// `(year, month)` from the file name; `None` for the template.
+    pub month: Option<(u32, u32)>,
+    pub author_name: Option<Setting>,
+    pub author_email: Option<Setting>,
+    pub video_url: Option<Setting>,
+    pub sections: Vec<TmilSection>,
+}
+
+impl TmilSource {
+    /// Items of the section whose English name is `en`.
+    pub fn section_items(&self, en: &str) -> &[TmilItem] {
+        self.sections
+            .iter()
+            .find(|section| section.names().is_some_and(|names| names.en == en))
+            .map(|section| section.items.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+pub fn read_tmil(path: &Path) -> io::Result<TmilSource> {
+    let content = fs::read_to_string(path)?;
+    let stem = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or_default();
+    Ok(parse_tmil(&content, tmil_month(stem)))
+}
+
+pub fn parse_tmil(content: &str, month: Option<(u32, u32)>) -> TmilSource {
+    TmilSource {
+        month,
+        author_name: let_string(content, "author_name"),
+        author_email: let_string(content, "author_email"),
+        video_url: let_string(content, "video_url"),
+        sections: parse_sections(content),
+    }
+}
+
+/// 1-based line of byte `offset`.
+fn line_of(content: &str, offset: usize) -> usize {
+    content[..offset].matches('\n').count() + 1
+}
+
+fn let_string(content: &str, key: &str) -> Option<Setting> {
+    let marker = format!("#let {key} = ");
+    let start = content.find(&marker)?;
+    let value_start = start + marker.len();
+    let value = content[value_start..].lines().next()?;
+    let value = string_literals(value).into_iter().next()?;
+    Some(Setting {
+        line: line_of(content, start),
+        value,
+    })
+}
+
+/// Offset just past the `)` closing the `(` at `open`. Code mode: string
+/// literals and `//` comments are skipped.
+fn close_paren(content: &str, open: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut chars = content[open..].char_indices().peekable();
+    while let Some((idx, ch)) = chars.next() {
+        match ch {
+            '"' => {
+                let mut escaped = false;
+                for (_, ch) in chars.by_ref() {
+                    match ch {
+                        _ if escaped => escaped = false,
+                        '\\' => escaped = true,
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+            }
+            '/' if chars.peek().is_some_and(|(_, next)| *next == '/') => {
+                for (_, ch) in chars.by_ref() {
+                    if ch == '\n' {
+                        break;
+                    }
+                }
+            }
+            '(' | '[' => depth += 1,
+            ')' | ']' => {
+                depth = depth.checked_sub(1)?;
+                if depth == 0 {
+                    return Some(open + idx + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Offset just past the `]` closing the content block `[` at `open`. Markup
+/// mode: quotes are plain text, `\[` and `\]` are escapes.
+fn close_bracket(content: &str, open: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut escaped = false;
+    for (idx, ch) in content[open..].char_indices() {
+        match ch {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '[' => depth += 1,
+            ']' => {
+                depth = depth.checked_sub(1)?;
+                if depth == 0 {
+                    return Some(open + idx + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Every string literal in a piece of code, unescaped, in order.
+fn string_literals(code: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut chars = code.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '"' {
+            continue;
+        }
+        let mut value = String::new();
+        let mut escaped = false;
+        for ch in chars.by_ref() {
+            match ch {
+                _ if escaped => {
+                    value.push(match ch {
+                        'n' => '\n',
+                        't' => '\t',
+                        other => other,
+                    });
+                    escaped = false;
+                }
+                '\\' => escaped = true,
+                '"' => break,
+                _ => value.push(ch),
+            }
+        }
+        out.push(value);
+    }
+    out
+}
+
+/// Top-level comma-separated arguments of a call's argument list.
+fn split_args(args: &str) -> Vec<&str> {
+    let mut out = Vec::new();
+    let mut start = 0;
+    let mut idx = 0;
+    while idx < args.len() {
+        let ch = args[idx..].chars().next().unwrap_or_default();
+        match ch {
+            '(' | '[' | '"' => {
+                // Strings are skipped like a bracketed group.
+                let end = if ch == '"' {
+                    args[idx + 1..]
+                        .find('"')
+                        .map(|rel| idx + rel + 2)
+                        .unwrap_or(args.len())
+                } else {
+                    close_paren(args, idx).unwrap_or(args.len())
+                };
+                idx = end;
+                continue;
+            }
+            ',' => {
+                out.push(args[start..idx].trim());
+                start = idx + 1;
+            }
+            _ => {}
+        }
+        idx += ch.len_utf8();
+    }
+    out.push(args[start..].trim());
+    out.retain(|arg| !arg.is_empty());
+    out
+}
+
+fn parse_heading(arg: &str) -> Heading {
+    let mut strings = string_literals(arg).into_iter();
+    Heading {
+        title: strings.next().unwrap_or_default(),
+        subtitle: strings.next().unwrap_or_default(),
+    }
+}
+
+/// Strip the indentation shared by every non-empty line.
+fn dedent(text: &str) -> String {
+    let indent = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    text.lines()
+        .map(|line| line.get(indent..).unwrap_or(line.trim_start()))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+/// Every `tmil_item(…)[…]` inside `content[start..end]`.
+fn parse_items(content: &str, start: usize, end: usize) -> Vec<TmilItem> {
+    let mut items = Vec::new();
+    let mut cursor = start;
+    while let Some(rel) = content[cursor..end].find("tmil_item(") {
+        let call = cursor + rel;
+        let open = call + "tmil_item".len();
+        let Some(close) = close_paren(content, open) else {
+            break;
+        };
+        let args = split_args(&content[open + 1..close - 1]);
+
+        let heading = args
+            .first()
+            .and_then(|arg| arg.strip_prefix("tmil_tr("))
+            .and_then(|tr| tr.strip_suffix(')'))
+            .map(|tr| {
+                let mut languages = split_args(tr).into_iter().map(parse_heading);
+                Localized {
+                    en: languages.next().unwrap_or_default(),
+                    pt_br: languages.next().unwrap_or_default(),
+                    zh_cn: languages.next().unwrap_or_default(),
+                }
+            });
+        let photo = args
+            .iter()
+            .find_map(|arg| arg.strip_prefix("photo:"))
+            .and_then(|photo| string_literals(photo).into_iter().next());
+
+        let after = close + content[close..].len() - content[close..].trim_start().len();
+        let (body, next) = match content[after..].starts_with('[') {
+            true => match close_bracket(content, after) {
+                Some(body_end) => (dedent(&content[after + 1..body_end - 1]), body_end),
+                None => (String::new(), close),
+            },
+            false => (String::new(), close),
+        };
+        items.push(TmilItem {
+            line: line_of(content, call),
+            heading,
+            photo,
+            body,
+        });
+        cursor = next;
+    }
+    items
+}
+
+/// Sections listed in `#let sections = (…)`, each with the items of the
+/// `#let <items> = (…)` block it names.
+fn parse_sections(content: &str) -> Vec<TmilSection> {
+    let marker = "#let sections = ";
+    let Some(open) = content.find(marker).map(|start| start + marker.len()) else {
+        return Vec::new();
+    };
+    let Some(close) = close_paren(content, open) else {
+        return Vec::new();
+    };
+
+    let mut sections = Vec::new();
+    let mut cursor = open;
+    while let Some(rel) = content[cursor..close].find("tmil_section(") {
+        let call = cursor + rel;
+        let args_open = call + "tmil_section".len();
+        let Some(args_close) = close_paren(content, args_open) else {
+            break;
+        };
+        let args_text = &content[args_open + 1..args_close - 1];
+        let args = split_args(args_text);
+        let name = args
+            .first()
+            .and_then(|arg| string_literals(arg).into_iter().next())
+            .unwrap_or_default();
+        let name_offset = args_text.find('"').map_or(call, |rel| args_open + 1 + rel);
+
+        let items = args
+            .iter()
+            .find_map(|arg| arg.strip_prefix("items:"))
+            .map(str::trim)
+            .and_then(|ident| {
+                let marker = format!("#let {ident} = ");
+                let block = content.find(&marker)? + marker.len();
+                let block_end = close_paren(content, block)?;
+                Some(parse_items(content, block, block_end))
+            })
+            .unwrap_or_default();
+
+        sections.push(TmilSection {
+            line: line_of(content, name_offset),
+            name,
+            items,
+        });
+        cursor = args_close;
+    }
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Heading, TMIL_TEMPLATE, read_tmil};
+    use std::path::Path;
+
+    #[test]
+    fn tmil_sources_are_parsed_into_sections_and_items() {
+        let source = read_tmil(Path::new("content/blog/posts/2026/2026_01_tmil.typ")).unwrap();
+        assert_eq!(source.month, Some((2026, 1)));
+        assert_eq!(
+            source.author_email.as_ref().unwrap().value,
+            "xaviduds@gmail.com"
+        );
+        assert_eq!(source.sections.len(), 3);
+        assert_eq!(
+            source.sections[0].names().unwrap().zh_cn,
+            "成长工作",
+            "Section names are written pt-BR | zh-CN | en"
+        );
+
+        let growth = &source.sections[0].items;
+        assert_eq!(growth.len(), 2);
+        let heading = growth[0].heading.as_ref().unwrap();
+        assert_eq!(heading.en.title, "Lince Institute in Brazil");
+        assert_eq!(heading.pt_br.title, "Instituto Lince no Brasil");
+        assert_eq!(
+            heading.zh_cn.subtitle,
+            "章程草案（Estatuto Social）+ 正在联系协助创建"
+        );
+        assert_eq!(
+            growth[0].photo.as_deref(),
+            Some("random/institute_depenency.jpg")
+        );
+        assert!(
+            growth[0]
+                .body
+                .starts_with("The creation of the Lince Institute")
+        );
+        assert_eq!(growth[0].line, 16);
+
+        let roadmap = &source.sections[2].items;
+        assert_eq!(roadmap.len(), 1);
+        assert_eq!(roadmap[0].heading, None);
+        assert!(roadmap[0].body.starts_with("- [/] v1.0.0: Todo"));
+
+        let template = read_tmil(Path::new(TMIL_TEMPLATE)).unwrap();
+        assert_eq!(template.month, None);
+        assert_eq!(
+            template.sections[0].items[0].heading.as_ref().unwrap().en,
+            Heading {
+                title: "Title".to_string(),
+                subtitle: "Optional subtitle".to_string()
+            }
+        );
+    }
+}